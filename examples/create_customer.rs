@@ -21,7 +21,9 @@ fn main() {
             business_vat_id: None,
             coupon: None,
             description: None,
+            invoice_settings: None,
             metadata: None,
+            payment_method: None,
             shipping: None,
         },
     ).unwrap();
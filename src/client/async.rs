@@ -0,0 +1,194 @@
+use client::{blocking, Params, RetryPolicy};
+use error::Error;
+use params::List;
+use futures::future;
+use futures::Future;
+use futures_cpupool::CpuPool;
+use serde;
+use serde_qs as qs;
+use std::sync::Arc;
+
+/// The future returned by a request made with the `AsyncClient`.
+pub type AsyncResponse<T> = Box<dyn Future<Item = T, Error = Error> + Send>;
+
+/// A futures-based client for the Stripe API.
+///
+/// Requests are sent with a blocking `Client` from a thread pool owned by the client,
+/// so the returned futures can be driven by an event loop without ever blocking it.
+/// This isn't non-blocking I/O: every request in flight holds a thread of the pool.
+///
+/// The methods of the resources take a blocking `Client`, so they are called with `AsyncClient::request`.
+#[derive(Clone)]
+pub struct AsyncClient {
+    inner: Arc<blocking::Client>,
+    pool: CpuPool,
+}
+
+impl AsyncClient {
+    /// Creates a new client with a thread pool of one thread per cpu.
+    pub fn new<Str: Into<String>>(secret_key: Str) -> AsyncClient {
+        AsyncClient::from_blocking(blocking::Client::new(secret_key), CpuPool::new_num_cpus())
+    }
+
+    /// Creates a new client which sends requests with `client` from the threads in `pool`.
+    ///
+    /// This is useful to share a single thread pool between multiple clients,
+    /// or to use a client configured with `Client::builder`.
+    pub fn from_blocking(client: blocking::Client, pool: CpuPool) -> AsyncClient {
+        AsyncClient {
+            inner: Arc::new(client),
+            pool,
+        }
+    }
+
+    /// Returns the blocking client used to send requests.
+    pub fn blocking(&self) -> &blocking::Client {
+        &self.inner
+    }
+
+    /// Clones a new client which sends requests to the files API (ie. "https://files.stripe.com").
+    pub fn files(&self) -> AsyncClient {
+        AsyncClient {
+            inner: Arc::new(self.inner.files()),
+            pool: self.pool.clone(),
+        }
+    }
+
+    /// Clones a new client which sends requests to the connect API (ie. "https://connect.stripe.com").
+    pub fn connect(&self) -> AsyncClient {
+        AsyncClient {
+            inner: Arc::new(self.inner.connect()),
+            pool: self.pool.clone(),
+        }
+    }
+
+    /// Clones a new client with different params.
+    ///
+    /// This is the recommended way to send requests for many different Stripe accounts
+    /// or with different Meta, Extra, and Expand params while using the same secret key.
+    pub fn with(&self, params: Params) -> AsyncClient {
+        AsyncClient {
+            inner: Arc::new(self.inner.with(params)),
+            pool: self.pool.clone(),
+        }
+    }
+
+    /// Sends a new random Idempotency-Key header with every POST and DELETE request.
+    ///
+    /// This is recommended to retry every request made with the client safely, and is kept by
+    /// the clients cloned with `client.with(params)`.
+    /// To send a known key for one logical request (eg. one retried by your own code), prefer
+    /// `client.with(Params{idempotency_key: Some(IdempotencyKey::Explicit(key)), ..})` instead.
+    pub fn enable_auto_idempotency(&mut self) {
+        Arc::make_mut(&mut self.inner).enable_auto_idempotency();
+    }
+
+    /// Sets a value for the Stripe-Account header
+    ///
+    /// This is recommended if you are acting as only one Account for the lifetime of the client.
+    /// Otherwise, prefer `client.with(Params{stripe_account: "acct_ABC", ..})`.
+    pub fn set_stripe_account<Str: Into<String>>(&mut self, account_id: Str) {
+        Arc::make_mut(&mut self.inner).set_stripe_account(account_id);
+    }
+
+    /// Sets the value of the Stripe-Version header, which defaults to `API_VERSION`.
+    ///
+    /// This is recommended if you are using only one version for the lifetime of the client.
+    /// Otherwise, prefer `client.with(Params{stripe_version: "2017-06-05", ..})`.
+    pub fn set_api_version<Str: Into<String>>(&mut self, version: Str) {
+        Arc::make_mut(&mut self.inner).set_api_version(version);
+    }
+
+    /// Sets the policy used to retry requests that failed with a transient error.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        Arc::make_mut(&mut self.inner).set_retry_policy(policy);
+    }
+
+    pub fn get<T: serde::de::DeserializeOwned + Send + 'static>(&self, path: &str) -> AsyncResponse<T> {
+        let path = path.to_string();
+        self.spawn(move |client| client.get(&path))
+    }
+
+    pub fn get_query<T, P>(&self, path: &str, params: P) -> AsyncResponse<T>
+    where
+        T: serde::de::DeserializeOwned + Send + 'static,
+        P: serde::Serialize,
+    {
+        match qs::to_string(&params) {
+            Ok(query) => self.get(&format!("{}?{}", path, query)),
+            Err(err) => Box::new(future::err(Error::from(err))),
+        }
+    }
+
+    pub fn get_list<T, P>(&self, path: &str, params: P) -> AsyncResponse<List<T>>
+    where
+        T: serde::de::DeserializeOwned + Send + 'static,
+        P: serde::Serialize,
+    {
+        let query = match qs::to_string(&params) {
+            Ok(query) => query,
+            Err(err) => return Box::new(future::err(Error::from(err))),
+        };
+        let path = format!("{}?{}", path, query);
+        self.spawn(move |client| {
+            let mut list: List<T> = client.get(&path)?;
+            list.query = query;
+            Ok(list)
+        })
+    }
+
+    pub fn post<T, P>(&self, path: &str, params: P) -> AsyncResponse<T>
+    where
+        T: serde::de::DeserializeOwned + Send + 'static,
+        P: serde::Serialize,
+    {
+        let body = match qs::to_string(&params) {
+            Ok(body) => body,
+            Err(err) => return Box::new(future::err(Error::from(err))),
+        };
+        let path = path.to_string();
+        self.spawn(move |client| client.post_encoded(&path, &body))
+    }
+
+    pub fn post_empty<T: serde::de::DeserializeOwned + Send + 'static>(&self, path: &str) -> AsyncResponse<T> {
+        let path = path.to_string();
+        self.spawn(move |client| client.post_empty(&path))
+    }
+
+    pub fn delete<T: serde::de::DeserializeOwned + Send + 'static>(&self, path: &str) -> AsyncResponse<T> {
+        let path = path.to_string();
+        self.spawn(move |client| client.delete(&path))
+    }
+
+    pub fn delete_query<T, P>(&self, path: &str, params: P) -> AsyncResponse<T>
+    where
+        T: serde::de::DeserializeOwned + Send + 'static,
+        P: serde::Serialize,
+    {
+        match qs::to_string(&params) {
+            Ok(query) => self.delete(&format!("{}?{}", path, query)),
+            Err(err) => Box::new(future::err(Error::from(err))),
+        }
+    }
+
+    /// Sends any request of the blocking API from the client's thread pool.
+    ///
+    /// This is how resources are used with the async client, eg.
+    /// `client.request(|client| Customer::retrieve(client, "cus_123"))`.
+    pub fn request<T, F>(&self, request: F) -> AsyncResponse<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&blocking::Client) -> Result<T, Error> + Send + 'static,
+    {
+        self.spawn(request)
+    }
+
+    fn spawn<T, F>(&self, request: F) -> AsyncResponse<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&blocking::Client) -> Result<T, Error> + Send + 'static,
+    {
+        let client = self.inner.clone();
+        Box::new(self.pool.spawn_fn(move || request(&client)))
+    }
+}
@@ -0,0 +1,368 @@
+use client::{API_VERSION, IdempotencyKey, Params, RetryPolicy};
+use client::retry::is_retryable;
+use error::{Error, ErrorObject, RequestError};
+use params::List;
+use hyper;
+use hyper::client::RequestBuilder;
+use hyper::header::{Authorization, Basic, ContentType, Headers};
+use hyper::method::Method;
+use hyper::net::HttpsConnector;
+use serde;
+use serde_json as json;
+use serde_qs as qs;
+use std::io::Read;
+use std::thread;
+use uuid::Uuid;
+
+const DEFAULT_API_BASE: &str = "https://api.stripe.com/v1";
+const DEFAULT_FILES_BASE: &str = "https://files.stripe.com/v1";
+const DEFAULT_CONNECT_BASE: &str = "https://connect.stripe.com";
+
+#[derive(Clone)]
+struct Hosts {
+    api: String,
+    files: String,
+    connect: String,
+}
+
+/// A builder used to configure a `Client` before it is created.
+///
+/// This is mainly useful to send requests to a local mock server (eg. stripe-mock) instead of Stripe.
+pub struct ClientBuilder {
+    secret_key: String,
+    api_version: String,
+    hosts: Hosts,
+    retry_policy: RetryPolicy,
+}
+
+impl ClientBuilder {
+    pub fn new<Str: Into<String>>(secret_key: Str) -> ClientBuilder {
+        ClientBuilder {
+            secret_key: secret_key.into(),
+            api_version: API_VERSION.to_string(),
+            hosts: Hosts {
+                api: DEFAULT_API_BASE.to_string(),
+                files: DEFAULT_FILES_BASE.to_string(),
+                connect: DEFAULT_CONNECT_BASE.to_string(),
+            },
+            retry_policy: RetryPolicy::never(),
+        }
+    }
+
+    /// Sets the base url used for API requests (eg. "http://localhost:12111/v1").
+    pub fn api_base<Str: Into<String>>(mut self, url: Str) -> ClientBuilder {
+        self.hosts.api = trim_base(url.into());
+        self
+    }
+
+    /// Sets the base url used for requests made with `client.files()`.
+    pub fn files_base<Str: Into<String>>(mut self, url: Str) -> ClientBuilder {
+        self.hosts.files = trim_base(url.into());
+        self
+    }
+
+    /// Sets the base url used for requests made with `client.connect()`.
+    pub fn connect_base<Str: Into<String>>(mut self, url: Str) -> ClientBuilder {
+        self.hosts.connect = trim_base(url.into());
+        self
+    }
+
+    /// Sets the value of the Stripe-Version header, which defaults to `API_VERSION`.
+    ///
+    /// The resources in this crate may fail to deserialize responses from other versions.
+    pub fn api_version<Str: Into<String>>(mut self, version: Str) -> ClientBuilder {
+        self.api_version = version.into();
+        self
+    }
+
+    /// Sets the policy used to retry requests that failed with a transient error.
+    ///
+    /// By default, requests are never retried.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> ClientBuilder {
+        self.retry_policy = policy;
+        self
+    }
+
+    pub fn build(self) -> Client {
+        Client {
+            client: hyper_client(),
+            secret_key: self.secret_key,
+            api_version: self.api_version,
+            params: Params::default(),
+            base: self.hosts.api.clone(),
+            hosts: self.hosts,
+            retry_policy: self.retry_policy,
+            auto_idempotency: false,
+        }
+    }
+}
+
+fn trim_base(mut url: String) -> String {
+    while url.ends_with('/') {
+        url.pop();
+    }
+    url
+}
+
+#[cfg(feature = "with-rustls")]
+fn hyper_client() -> hyper::Client {
+    use hyper_rustls::TlsClient;
+
+    let tls = TlsClient::new();
+    let connector = HttpsConnector::new(tls);
+    hyper::Client::with_connector(connector)
+}
+
+#[cfg(feature = "with-openssl")]
+fn hyper_client() -> hyper::Client {
+    use hyper_openssl::OpensslClient;
+
+    let tls = OpensslClient::new().unwrap();
+    let connector = HttpsConnector::new(tls);
+    hyper::Client::with_connector(connector)
+}
+
+// TODO: #[derive(Clone)]
+pub struct Client {
+    client: hyper::Client,
+    secret_key: String,
+    api_version: String,
+    params: Params,
+    base: String,
+    hosts: Hosts,
+    retry_policy: RetryPolicy,
+    auto_idempotency: bool,
+}
+
+// TODO: With Hyper 0.11.x, hyper::Client implements clone, and we can just derive this
+impl Clone for Client {
+    fn clone(&self) -> Self {
+        Client {
+            client: hyper_client(),
+            secret_key: self.secret_key.clone(),
+            api_version: self.api_version.clone(),
+            params: self.params.clone(),
+            base: self.base.clone(),
+            hosts: self.hosts.clone(),
+            retry_policy: self.retry_policy.clone(),
+            auto_idempotency: self.auto_idempotency,
+        }
+    }
+}
+
+impl Client {
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base, path)
+    }
+
+    pub fn new<Str: Into<String>>(secret_key: Str) -> Client {
+        ClientBuilder::new(secret_key).build()
+    }
+
+    /// Returns a builder to configure the client's base urls.
+    pub fn builder<Str: Into<String>>(secret_key: Str) -> ClientBuilder {
+        ClientBuilder::new(secret_key)
+    }
+
+    /// Clones a new client which sends requests to the files API (ie. "https://files.stripe.com").
+    pub fn files(&self) -> Client {
+        let mut client = self.clone();
+        client.base = self.hosts.files.clone();
+        client
+    }
+
+    /// Clones a new client which sends requests to the connect API (ie. "https://connect.stripe.com").
+    pub fn connect(&self) -> Client {
+        let mut client = self.clone();
+        client.base = self.hosts.connect.clone();
+        client
+    }
+
+    /// Clones a new client with different params.
+    ///
+    /// This is the recommended way to send requests for many different Stripe accounts
+    /// or with different Meta, Extra, and Expand params while using the same secret key.
+    pub fn with(&self, params: Params) -> Client {
+        let mut client = self.clone();
+        client.params = params;
+        client
+    }
+
+    /// Sends a new random Idempotency-Key header with every POST and DELETE request.
+    ///
+    /// This is recommended to retry every request made with the client safely, and is kept by
+    /// the clients cloned with `client.with(params)`.
+    /// To send a known key for one logical request (eg. one retried by your own code), prefer
+    /// `client.with(Params{idempotency_key: Some(IdempotencyKey::Explicit(key)), ..})` instead.
+    pub fn enable_auto_idempotency(&mut self) {
+        self.auto_idempotency = true;
+    }
+
+    /// Sets a value for the Stripe-Account header
+    ///
+    /// This is recommended if you are acting as only one Account for the lifetime of the client.
+    /// Otherwise, prefer `client.with(Params{stripe_account: "acct_ABC", ..})`.
+    pub fn set_stripe_account<Str: Into<String>>(&mut self, account_id: Str) {
+        self.params.stripe_account = Some(account_id.into());
+    }
+
+    /// Sets the value of the Stripe-Version header, which defaults to `API_VERSION`.
+    ///
+    /// This is recommended if you are using only one version for the lifetime of the client.
+    /// Otherwise, prefer `client.with(Params{stripe_version: "2017-06-05", ..})`.
+    pub fn set_api_version<Str: Into<String>>(&mut self, version: Str) {
+        self.api_version = version.into();
+    }
+
+    /// Sets the policy used to retry requests that failed with a transient error.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry_policy = policy;
+    }
+
+    pub fn get<T: serde::de::DeserializeOwned>(&self, path: &str) -> Result<T, Error> {
+        self.send(Method::Get, path, None)
+    }
+
+    pub fn get_query<T: serde::de::DeserializeOwned, P: serde::Serialize>(
+        &self,
+        path: &str,
+        params: P,
+    ) -> Result<T, Error> {
+        let path = format!("{}?{}", path, qs::to_string(&params)?);
+        self.get(&path)
+    }
+
+    pub fn get_list<T: serde::de::DeserializeOwned, P: serde::Serialize>(
+        &self,
+        path: &str,
+        params: P,
+    ) -> Result<List<T>, Error> {
+        let query = qs::to_string(&params)?;
+        let mut list: List<T> = self.get(&format!("{}?{}", path, query))?;
+        list.query = query;
+        Ok(list)
+    }
+
+    pub fn post<T: serde::de::DeserializeOwned, P: serde::Serialize>(&self, path: &str, params: P) -> Result<T, Error> {
+        let body = qs::to_string(&params)?;
+        self.post_encoded(path, &body)
+    }
+
+    pub fn post_empty<T: serde::de::DeserializeOwned>(&self, path: &str) -> Result<T, Error> {
+        self.send(Method::Post, path, None)
+    }
+
+    pub fn delete<T: serde::de::DeserializeOwned>(&self, path: &str) -> Result<T, Error> {
+        self.send(Method::Delete, path, None)
+    }
+
+    pub fn delete_query<T: serde::de::DeserializeOwned, P: serde::Serialize>(
+        &self,
+        path: &str,
+        params: P,
+    ) -> Result<T, Error> {
+        let path = format!("{}?{}", path, qs::to_string(&params)?);
+        self.delete(&path)
+    }
+
+    /// Sends a POST request with a body that has already been form-encoded.
+    pub(crate) fn post_encoded<T: serde::de::DeserializeOwned>(&self, path: &str, body: &str) -> Result<T, Error> {
+        self.send(Method::Post, path, Some(body))
+    }
+
+    /// Sends a request, retrying it according to the client's retry policy.
+    ///
+    /// POST requests are only retried when they carry an `Idempotency-Key` header,
+    /// since otherwise Stripe could apply them more than once.
+    fn send<T: serde::de::DeserializeOwned>(&self, method: Method, path: &str, body: Option<&str>) -> Result<T, Error> {
+        let mut url = self.url(path);
+        let mut body = body.map(|body| body.to_string());
+        if !self.params.expand.is_empty() {
+            let expand = qs::to_string(&ExpandParams { expand: &self.params.expand })?;
+            if method == Method::Post {
+                body = Some(match body {
+                    Some(ref body) if !body.is_empty() => format!("{}&{}", body, expand),
+                    _ => expand,
+                });
+            } else {
+                let separator = if url.contains('?') { '&' } else { '?' };
+                url = format!("{}{}{}", url, separator, expand);
+            }
+        }
+        let mut headers = self.headers();
+        if method != Method::Get {
+            let key = match self.params.idempotency_key {
+                Some(IdempotencyKey::Explicit(ref key)) => Some(key.clone()),
+                Some(IdempotencyKey::Auto) => Some(Uuid::new_v4().hyphenated().to_string()),
+                None if self.auto_idempotency => Some(Uuid::new_v4().hyphenated().to_string()),
+                None => None,
+            };
+            if let Some(key) = key {
+                headers.set_raw("Idempotency-Key", vec![key.into_bytes()]);
+            }
+        }
+        let idempotent = method != Method::Post || headers.get_raw("Idempotency-Key").is_some();
+        let mut attempt = 1;
+        loop {
+            let mut request = self.client.request(method.clone(), &url).headers(headers.clone());
+            if let Some(ref body) = body {
+                request = request.body(body.as_str());
+            }
+            match send(request) {
+                Ok(value) => return Ok(value),
+                Err((err, should_retry)) => {
+                    if !idempotent || attempt >= self.retry_policy.max_attempts || !is_retryable(&err, should_retry) {
+                        return Err(err);
+                    }
+                    thread::sleep(self.retry_policy.backoff(attempt));
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    fn headers(&self) -> Headers {
+        let mut headers = Headers::new();
+        headers.set(Authorization(Basic {
+            username: self.secret_key.clone(),
+            password: None,
+        }));
+        headers.set(ContentType::form_url_encoded());
+        let version = self.params.stripe_version.as_ref().unwrap_or(&self.api_version);
+        headers.set_raw("Stripe-Version", vec![version.as_bytes().to_vec()]);
+        if let Some(ref account) = self.params.stripe_account {
+            headers.set_raw("Stripe-Account", vec![account.as_bytes().to_vec()]);
+        }
+        headers
+    }
+}
+
+#[derive(Serialize)]
+struct ExpandParams<'a> {
+    expand: &'a [String],
+}
+
+/// Sends a request once, returning the value of the `Stripe-Should-Retry` header along with any error.
+fn send<T: serde::de::DeserializeOwned>(request: RequestBuilder) -> Result<T, (Error, Option<bool>)> {
+    let mut response = request.send().map_err(|err| (Error::from(err), None))?;
+    let should_retry = response.headers.get_raw("Stripe-Should-Retry").and_then(|values| {
+        values.first().map(|value| value.as_slice() == b"true")
+    });
+    let mut body = String::with_capacity(4096);
+    response.read_to_string(&mut body).map_err(|err| (Error::from(err), should_retry))?;
+    let status = response.status_raw().0;
+    match status {
+        200...299 => {}
+        _ => {
+            let mut err = json::from_str(&body).unwrap_or_else(|err| {
+                let mut req = ErrorObject { error: RequestError::default() };
+                req.error.message = Some(format!("failed to deserialize error: {}", err));
+                req
+            });
+            err.error.http_status = status;
+            return Err((Error::from(err.error), should_retry));
+        }
+    }
+
+    json::from_str(&body).map_err(|err| (Error::from(err), None))
+}
@@ -0,0 +1,38 @@
+#[cfg(feature = "async")]
+mod async;
+mod blocking;
+mod retry;
+
+#[cfg(feature = "async")]
+pub use self::async::{AsyncClient, AsyncResponse};
+pub use self::blocking::{Client, ClientBuilder};
+pub use self::retry::RetryPolicy;
+
+/// The version of the Stripe API which the resources in this crate are modeled after.
+///
+/// It is sent in the Stripe-Version header of every request unless it is overridden,
+/// so that responses keep their shape when an account's default API version changes.
+pub const API_VERSION: &str = "2019-02-11";
+
+#[derive(Clone, Default)]
+pub struct Params {
+    pub stripe_account: Option<String>,
+    pub stripe_version: Option<String>,
+    pub idempotency_key: Option<IdempotencyKey>,
+    pub expand: Vec<String>,
+}
+
+/// The value sent in the Idempotency-Key header of POST and DELETE requests.
+///
+/// The same key is sent again when a request is retried, so that Stripe only applies it once.
+/// For more details see https://stripe.com/docs/api#idempotent_requests.
+#[derive(Clone, Debug)]
+pub enum IdempotencyKey {
+    /// Sends the given key with each request.
+    ///
+    /// A key identifies one logical request, so only use it with a client from `Client::with`
+    /// which sends that one request; Stripe replays the first response to every later request with the key.
+    Explicit(String),
+    /// Generates a new random (UUID v4) key for each request.
+    Auto,
+}
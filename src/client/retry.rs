@@ -0,0 +1,100 @@
+use error::{Error, ErrorType};
+use hyper;
+use rand;
+use std::cmp;
+use std::time::Duration;
+
+/// The policy used by a `Client` to retry requests that failed with a transient error.
+///
+/// A request is retried when it failed to connect to Stripe, when Stripe responded with a
+/// `rate_limit_error` or `api_error`, or when Stripe set the `Stripe-Should-Retry` header.
+/// POST requests are only retried if they were sent with an idempotency key.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// The maximum number of times a request is sent, including the first attempt.
+    pub max_attempts: u32,
+    /// The delay before the first retry, which is doubled for every subsequent retry.
+    pub backoff_base: Duration,
+    /// The maximum delay between two attempts.
+    pub backoff_cap: Duration,
+    /// Whether to randomize each delay between half and all of its computed value.
+    pub jitter: bool,
+}
+
+impl RetryPolicy {
+    /// A policy which never retries requests.
+    pub fn never() -> RetryPolicy {
+        RetryPolicy { max_attempts: 1, ..RetryPolicy::default() }
+    }
+
+    /// Returns how long to wait before sending the request again after `attempt` failed attempts.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = cmp::min(attempt.saturating_sub(1), 31);
+        let delay = self.backoff_base.checked_mul(1 << exponent).unwrap_or(self.backoff_cap);
+        let delay = cmp::min(delay, self.backoff_cap);
+        if !self.jitter {
+            return delay;
+        }
+
+        let millis = delay.as_secs() * 1000 + u64::from(delay.subsec_millis());
+        let jittered = (millis as f64 / 2.0) * (1.0 + rand::random::<f64>());
+        Duration::from_millis(jittered as u64)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            backoff_base: Duration::from_millis(500),
+            backoff_cap: Duration::from_secs(5),
+            jitter: true,
+        }
+    }
+}
+
+/// Returns whether a failed request may succeed if it is sent again.
+///
+/// The `Stripe-Should-Retry` header, when present, takes precedence over the kind of error.
+pub fn is_retryable(err: &Error, should_retry: Option<bool>) -> bool {
+    if let Some(should_retry) = should_retry {
+        return should_retry;
+    }
+
+    match *err {
+        Error::Http(hyper::Error::Io(_)) | Error::Io(_) => true,
+        Error::Stripe(ref err) => {
+            err.error_type == ErrorType::RateLimit || err.error_type == ErrorType::Api || err.http_status == 429 ||
+                err.http_status >= 500
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RetryPolicy;
+    use std::time::Duration;
+
+    #[test]
+    fn backoff() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            backoff_base: Duration::from_millis(100),
+            backoff_cap: Duration::from_millis(1000),
+            jitter: false,
+        };
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(4), Duration::from_millis(800));
+        assert_eq!(policy.backoff(5), Duration::from_millis(1000));
+        assert_eq!(policy.backoff(100), Duration::from_millis(1000));
+
+        let policy = RetryPolicy { jitter: true, ..policy };
+        for attempt in 1..10 {
+            let delay = policy.backoff(attempt);
+            assert!(delay >= Duration::from_millis(50));
+            assert!(delay <= Duration::from_millis(1000));
+        }
+    }
+}
@@ -82,6 +82,50 @@ impl From<json::Error> for Error {
     }
 }
 
+/// An error encountered when verifying the signature of a webhook.
+#[derive(Debug)]
+pub enum WebhookError {
+    /// The Stripe-Signature header is missing a timestamp or could not be parsed.
+    BadHeader,
+    /// The Stripe-Signature header doesn't contain any `v1` signatures.
+    NoSignatures,
+    /// None of the signatures match the payload signed with the endpoint's secret.
+    BadSignature,
+    /// The webhook's timestamp is outside of the tolerated interval.
+    BadTimestamp(i64),
+    /// The payload isn't a valid event.
+    BadParse(json::Error),
+}
+
+impl fmt::Display for WebhookError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(error::Error::description(self))?;
+        match *self {
+            WebhookError::BadTimestamp(timestamp) => write!(f, ": {}", timestamp),
+            WebhookError::BadParse(ref err) => write!(f, ": {}", err),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl error::Error for WebhookError {
+    fn description(&self) -> &str {
+        match *self {
+            WebhookError::BadHeader => "error parsing the webhook's signature header",
+            WebhookError::NoSignatures => "no v1 signatures in the webhook's signature header",
+            WebhookError::BadSignature => "no signature matches the webhook's payload",
+            WebhookError::BadTimestamp(_) => "the webhook's timestamp is outside of the tolerance",
+            WebhookError::BadParse(_) => "error parsing the webhook's payload as an event",
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn error::Error> {
+        match *self {
+            WebhookError::BadParse(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 /// The list of possible values for a RequestError's type.
 #[derive(Debug, PartialEq, Deserialize)]
@@ -6,23 +6,37 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+#[cfg(feature = "async")]
+extern crate futures;
+#[cfg(feature = "async")]
+extern crate futures_cpupool;
+extern crate hmac;
 extern crate hyper;
 #[cfg(feature = "with-rustls")]
 extern crate hyper_rustls;
 #[cfg(feature = "with-openssl")]
 extern crate hyper_openssl;
+extern crate rand;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
 extern crate serde_json;
 extern crate serde_qs;
+extern crate sha2;
+extern crate uuid;
 
 mod client;
 mod error;
 mod resources;
 mod params;
+mod webhook;
 
-pub use client::{Client, Params};
-pub use error::{Error, ErrorCode, ErrorType, RequestError};
-pub use params::{List, Metadata, Timestamp};
+pub use client::{API_VERSION, Client, ClientBuilder, IdempotencyKey, Params, RetryPolicy};
+#[cfg(feature = "async")]
+pub use client::{AsyncClient, AsyncResponse};
+pub use error::{Error, ErrorCode, ErrorType, RequestError, WebhookError};
+pub use params::{Expandable, Identifiable, List, ListParams, Metadata, Paginator, RangeBounds, RangeQuery, Timestamp};
+#[cfg(feature = "async")]
+pub use params::AsyncPaginator;
 pub use resources::*;
+pub use webhook::{Webhook, DEFAULT_TOLERANCE};
@@ -1,4 +1,15 @@
+use client::Client;
+use error::Error;
+use serde;
+use serde::de::{Deserialize, Deserializer, Error as DeError};
+use serde_json as json;
 use std::collections::HashMap;
+use std::vec;
+
+#[cfg(feature = "async")]
+use client::{AsyncClient, AsyncResponse};
+#[cfg(feature = "async")]
+use futures::{Async, Poll, Stream};
 
 #[derive(Debug, Deserialize)]
 pub struct List<T> {
@@ -6,6 +17,310 @@ pub struct List<T> {
     pub has_more: bool,
     pub total_count: Option<u64>,
     pub url: String,
+
+    /// The query of the request which returned the list, used to request the following pages.
+    #[serde(skip_deserializing)]
+    pub(crate) query: String,
+}
+
+/// The set of parameters that can be used to paginate any list, and to filter it by creation date.
+///
+/// For more details see https://stripe.com/docs/api#pagination.
+#[derive(Clone, Default, Serialize)]
+pub struct ListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+}
+
+/// A filter matching either an exact value or a range of values (eg. `created[gte]=1502740000`).
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum RangeQuery<T> {
+    Exact(T),
+    Bounds(RangeBounds<T>),
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct RangeBounds<T> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gt: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gte: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lt: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lte: Option<T>,
+}
+
+impl<T> RangeBounds<T> {
+    fn none() -> RangeBounds<T> {
+        RangeBounds {
+            gt: None,
+            gte: None,
+            lt: None,
+            lte: None,
+        }
+    }
+}
+
+impl<T> RangeQuery<T> {
+    /// Matches values equal to `value`.
+    pub fn eq(value: T) -> RangeQuery<T> {
+        RangeQuery::Exact(value)
+    }
+
+    /// Matches values greater than `value`.
+    pub fn gt(value: T) -> RangeQuery<T> {
+        RangeQuery::Bounds(RangeBounds { gt: Some(value), ..RangeBounds::none() })
+    }
+
+    /// Matches values greater than or equal to `value`.
+    pub fn gte(value: T) -> RangeQuery<T> {
+        RangeQuery::Bounds(RangeBounds { gte: Some(value), ..RangeBounds::none() })
+    }
+
+    /// Matches values less than `value`.
+    pub fn lt(value: T) -> RangeQuery<T> {
+        RangeQuery::Bounds(RangeBounds { lt: Some(value), ..RangeBounds::none() })
+    }
+
+    /// Matches values less than or equal to `value`.
+    pub fn lte(value: T) -> RangeQuery<T> {
+        RangeQuery::Bounds(RangeBounds { lte: Some(value), ..RangeBounds::none() })
+    }
+
+    /// Matches values greater than or equal to `start` and less than `end`.
+    pub fn between(start: T, end: T) -> RangeQuery<T> {
+        RangeQuery::Bounds(RangeBounds { gte: Some(start), lt: Some(end), ..RangeBounds::none() })
+    }
+}
+
+/// A field which holds either the id of an object or, when it is expanded, the object itself.
+///
+/// Fields are expanded by setting `expand` in the client's `Params`, eg. `vec!["customer".to_string()]`.
+/// An expanded object which was deleted (eg. `{"id": "cus_123", "deleted": true}`) is kept as its id.
+/// For more details see https://stripe.com/docs/api#expanding_objects.
+#[derive(Debug)]
+pub enum Expandable<T> {
+    Id(String),
+    Object(Box<T>),
+}
+
+impl<'de, T: serde::de::DeserializeOwned> Deserialize<'de> for Expandable<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = json::Value::deserialize(deserializer)?;
+        if let json::Value::String(id) = value {
+            return Ok(Expandable::Id(id));
+        }
+        if value.get("deleted").and_then(|deleted| deleted.as_bool()) == Some(true) {
+            return match value.get("id").and_then(|id| id.as_str()) {
+                Some(id) => Ok(Expandable::Id(id.to_string())),
+                None => Err(D::Error::custom("expected the id of a deleted object")),
+            };
+        }
+        json::from_value(value).map(|object| Expandable::Object(Box::new(object))).map_err(D::Error::custom)
+    }
+}
+
+impl<T: Identifiable> Expandable<T> {
+    /// Returns the object if the field was expanded.
+    pub fn as_object(&self) -> Option<&T> {
+        match *self {
+            Expandable::Id(_) => None,
+            Expandable::Object(ref object) => Some(object),
+        }
+    }
+
+    /// Returns the object if the field was expanded.
+    pub fn into_object(self) -> Option<T> {
+        match self {
+            Expandable::Id(_) => None,
+            Expandable::Object(object) => Some(*object),
+        }
+    }
+}
+
+impl<T: Identifiable> Identifiable for Expandable<T> {
+    fn id(&self) -> &str {
+        match *self {
+            Expandable::Id(ref id) => id,
+            Expandable::Object(ref object) => object.id(),
+        }
+    }
+}
+
+/// A resource with an id, which is used as a cursor to paginate lists of the resource.
+pub trait Identifiable {
+    fn id(&self) -> &str;
+}
+
+impl<T: Identifiable> List<T> {
+    /// Returns a paginator over every item in the list, which lazily requests the following
+    /// pages with the same parameters as the request which returned the list.
+    ///
+    /// If the list was requested with `ending_before`, the paginator walks backwards
+    /// through the previous pages instead.
+    pub fn paginate(self, client: &Client) -> Paginator<T> {
+        Paginator {
+            client: client.clone(),
+            pages: self.pages(),
+        }
+    }
+
+    /// Returns a stream over every item in the list, which requests the following pages
+    /// with the async client the same way as `paginate`.
+    #[cfg(feature = "async")]
+    pub fn paginate_async(self, client: &AsyncClient) -> AsyncPaginator<T> {
+        AsyncPaginator {
+            client: client.clone(),
+            pages: self.pages(),
+            pending: None,
+        }
+    }
+
+    fn pages(self) -> Pages<T> {
+        // NOTE: Some urls have their own query, eg. "/v1/invoices/upcoming/lines?customer=cus_123"
+        let (url, url_query) = match self.url.find('?') {
+            Some(index) => (&self.url[..index], &self.url[index + 1..]),
+            None => (&self.url[..], ""),
+        };
+
+        let key = |pair: &str| pair.split('=').next().unwrap_or("").to_string();
+        let mut query = Vec::new();
+        let mut backwards = false;
+        for pair in self.query.split('&').filter(|pair| !pair.is_empty()) {
+            match key(pair).as_str() {
+                "ending_before" => backwards = true,
+                "starting_after" => {}
+                _ => query.push(pair),
+            }
+        }
+        let keys: Vec<String> = query.iter().map(|pair| key(pair)).collect();
+        for pair in url_query.split('&').filter(|pair| !pair.is_empty()) {
+            if !keys.contains(&key(pair)) {
+                query.push(pair);
+            }
+        }
+        let path = if url.starts_with("/v1/") { &url[3..] } else { url };
+
+        let mut pages = Pages {
+            path: path.to_string(),
+            query: query.join("&"),
+            backwards,
+            page: Vec::new().into_iter(),
+            cursor: None,
+            has_more: false,
+        };
+        pages.load(self);
+        pages
+    }
+}
+
+/// An iterator over every item in a paginated list.
+///
+/// For more details see https://stripe.com/docs/api#pagination.
+pub struct Paginator<T> {
+    client: Client,
+    pages: Pages<T>,
+}
+
+/// A `Stream` over every item in a paginated list, which requests pages with the async client.
+#[cfg(feature = "async")]
+pub struct AsyncPaginator<T> {
+    client: AsyncClient,
+    pages: Pages<T>,
+    pending: Option<AsyncResponse<List<T>>>,
+}
+
+/// The current page and the cursor to the next page of a paginated list,
+/// which is shared by `Paginator` and `AsyncPaginator`.
+struct Pages<T> {
+    path: String,
+    query: String,
+    backwards: bool,
+    page: vec::IntoIter<T>,
+    cursor: Option<String>,
+    has_more: bool,
+}
+
+impl<T: Identifiable> Pages<T> {
+    fn load(&mut self, list: List<T>) {
+        let cursor = if self.backwards { list.data.first() } else { list.data.last() };
+        self.cursor = cursor.map(|item| item.id().to_string());
+        self.has_more = list.has_more && self.cursor.is_some();
+        self.page = list.data.into_iter();
+    }
+
+    fn next_path(&self) -> String {
+        let cursor = self.cursor.as_deref().unwrap_or("");
+        let direction = if self.backwards { "ending_before" } else { "starting_after" };
+        if self.query.is_empty() {
+            format!("{}?{}={}", self.path, direction, cursor)
+        } else {
+            format!("{}?{}&{}={}", self.path, self.query, direction, cursor)
+        }
+    }
+}
+
+impl<T: Identifiable + serde::de::DeserializeOwned> Iterator for Paginator<T> {
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(item) = self.pages.page.next() {
+            return Some(Ok(item));
+        }
+        if !self.pages.has_more {
+            return None;
+        }
+
+        match self.client.get(&self.pages.next_path()) {
+            Ok(list) => {
+                self.pages.load(list);
+                self.pages.page.next().map(Ok)
+            }
+            Err(err) => {
+                self.pages.has_more = false;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: Identifiable + serde::de::DeserializeOwned + Send + 'static> Stream for AsyncPaginator<T> {
+    type Item = T;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Option<T>, Error> {
+        loop {
+            if let Some(item) = self.pages.page.next() {
+                return Ok(Async::Ready(Some(item)));
+            }
+
+            let mut pending = match self.pending.take() {
+                Some(pending) => pending,
+                None if self.pages.has_more => self.client.get(&self.pages.next_path()),
+                None => return Ok(Async::Ready(None)),
+            };
+            match pending.poll() {
+                Ok(Async::Ready(list)) => self.pages.load(list),
+                Ok(Async::NotReady) => {
+                    self.pending = Some(pending);
+                    return Ok(Async::NotReady);
+                }
+                Err(err) => {
+                    self.pages.has_more = false;
+                    return Err(err);
+                }
+            }
+        }
+    }
 }
 
 pub type Metadata = HashMap<String, String>;
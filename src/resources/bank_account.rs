@@ -0,0 +1,28 @@
+use params::{Identifiable, Metadata};
+use resources::Currency;
+
+/// The resource representing a Stripe bank account.
+///
+/// For more details see https://stripe.com/docs/api#customer_bank_account_object.
+#[derive(Debug, Deserialize)]
+pub struct BankAccount {
+    pub id: String,
+    pub account_holder_name: Option<String>,
+    pub account_holder_type: Option<String>, // (individual, company)
+    pub bank_name: Option<String>,
+    pub country: String, // eg. "US"
+    pub currency: Currency,
+    pub customer: Option<String>,
+    pub fingerprint: Option<String>,
+    pub last4: String,
+    #[serde(default)]
+    pub metadata: Metadata,
+    pub routing_number: Option<String>,
+    pub status: String, // (new, validated, verified, verification_failed, errored)
+}
+
+impl Identifiable for BankAccount {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
@@ -1,3 +1,5 @@
+use params::Identifiable;
+
 #[derive(Serialize)]
 pub struct CardParams<'a> {
     pub object: &'static str, // must be "card"
@@ -43,3 +45,9 @@ pub struct Card {
     pub funding: String, // (credit, debit, prepaid, unknown)
     pub last4: String,
 }
+
+impl Identifiable for Card {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
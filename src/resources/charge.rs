@@ -1,7 +1,7 @@
 use client::Client;
 use error::{Error, ErrorCode};
-use params::{List, Metadata, Timestamp};
-use resources::{Address, Currency, CustomerSource, Refund, Source};
+use params::{Expandable, Identifiable, List, ListParams, Metadata, Timestamp};
+use resources::{Address, Currency, Customer, CustomerSource, Invoice, Refund, Source};
 
 #[derive(Debug, Deserialize)]
 pub struct ChargeOutcome {
@@ -94,6 +94,19 @@ pub struct ChargeParams<'a> {
     pub statement_descriptor: Option<&'a str>,
 }
 
+/// The set of parameters that can be used when listing charges.
+///
+/// For more details see https://stripe.com/docs/api#list_charges.
+#[derive(Default, Serialize)]
+pub struct ChargeListParams<'a> {
+    #[serde(flatten)]
+    pub list: ListParams<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_group: Option<&'a str>,
+}
+
 /// The resource representing a Stripe charge.
 ///
 /// For more details see https://stripe.com/docs/api#charges.
@@ -108,26 +121,28 @@ pub struct Charge {
     pub captured: bool,
     pub created: Timestamp,
     pub currency: Currency,
-    pub customer: Option<String>,
+    pub customer: Option<Expandable<Customer>>,
     pub description: Option<String>,
     pub destination: Option<String>,
     pub dispute: Option<String>,
     pub failure_code: Option<ErrorCode>,
     pub failure_message: Option<String>,
     pub fraud_details: FraudDetails,
-    pub invoice: Option<String>,
+    pub invoice: Option<Expandable<Invoice>>,
     pub livemode: bool,
     pub metadata: Metadata,
     pub on_behalf_of: Option<String>,
     pub order: Option<String>,
     pub outcome: ChargeOutcome,
     pub paid: bool,
+    pub payment_intent: Option<String>,
+    pub payment_method: Option<String>,
     pub receipt_email: Option<String>,
     pub receipt_number: Option<String>,
     pub refunded: bool,
     pub refunds: List<Refund>,
     pub shipping: Option<ShippingDetails>,
-    pub source: Source,
+    pub source: Option<Source>, // NOTE: Missing for charges created by a payment intent
     pub source_transfer: Option<String>,
     pub statement_descriptor: Option<String>,
     pub status: String, // (succeeded, pending, failed)
@@ -156,6 +171,13 @@ impl Charge {
         client.post(&format!("/charges/{}", charge_id), params)
     }
 
+    /// Lists all charges, optionally filtered by customer, creation date or transfer group.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_charges.
+    pub fn list(client: &Client, params: ChargeListParams) -> Result<List<Charge>, Error> {
+        client.get_list("/charges", &params)
+    }
+
     /// Capture captures a previously created charge with capture set to false.
     ///
     /// For more details see https://stripe.com/docs/api#charge_capture.
@@ -163,3 +185,9 @@ impl Charge {
         client.post(&format!("/charges/{}/capture", charge_id), params)
     }
 }
+
+impl Identifiable for Charge {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
@@ -1,6 +1,36 @@
-use params::{Metadata, Timestamp};
-use resources::Currency;
+use client::Client;
+use error::Error;
+use params::{Identifiable, List, ListParams, Metadata, Timestamp};
+use resources::{Currency, Deleted};
 
+/// The set of parameters that can be used when creating or updating a coupon.
+///
+/// For more details see https://stripe.com/docs/api#create_coupon and https://stripe.com/docs/api#update_coupon.
+#[derive(Default, Serialize)]
+pub struct CouponParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_off: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>, // NOTE: Required with amount_off
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<&'a str>, // (forever, once, repeating)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_in_months: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_redemptions: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percent_off: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redeem_by: Option<Timestamp>,
+}
+
+/// The resource representing a Stripe coupon.
+///
+/// For more details see https://stripe.com/docs/api#coupons.
 #[derive(Debug, Deserialize)]
 pub struct Coupon {
     pub id: String,
@@ -12,9 +42,53 @@ pub struct Coupon {
     pub livemode: bool,
     pub max_redemptions: Option<u64>,
     pub metadata: Metadata,
-    pub percent_off: u64, // eg. 50 => 50%
-    pub redeem_by: Timestamp,
-    pub redeemed: u64,
+    pub percent_off: Option<f64>, // eg. 12.5 => 12.5%
+    pub redeem_by: Option<Timestamp>,
+    pub times_redeemed: u64,
     pub valid: bool,
+    #[serde(default)]
     pub deleted: bool,
 }
+
+impl Coupon {
+    /// Creates a new coupon.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_coupon.
+    pub fn create(client: &Client, params: CouponParams) -> Result<Coupon, Error> {
+        client.post("/coupons", params)
+    }
+
+    /// Retrieves the details of a coupon.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_coupon.
+    pub fn retrieve(client: &Client, coupon_id: &str) -> Result<Coupon, Error> {
+        client.get(&format!("/coupons/{}", coupon_id))
+    }
+
+    /// Updates a coupon's metadata.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_coupon.
+    pub fn update(client: &Client, coupon_id: &str, params: CouponParams) -> Result<Coupon, Error> {
+        client.post(&format!("/coupons/{}", coupon_id), params)
+    }
+
+    /// Deletes a coupon.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_coupon.
+    pub fn delete(client: &Client, coupon_id: &str) -> Result<Deleted, Error> {
+        client.delete(&format!("/coupons/{}", coupon_id))
+    }
+
+    /// Lists all coupons.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_coupons.
+    pub fn list(client: &Client, params: ListParams) -> Result<List<Coupon>, Error> {
+        client.get_list("/coupons", &params)
+    }
+}
+
+impl Identifiable for Coupon {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
@@ -1,7 +1,7 @@
 use error::Error;
 use client::Client;
-use resources::{Address, CardParams, Currency, Deleted, Discount, Source, Subscription};
-use params::{List, Metadata};
+use resources::{Address, BankAccount, CardParams, Currency, Deleted, Discount, PaymentMethod, Source, Subscription};
+use params::{Expandable, Identifiable, List, ListParams, Metadata};
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct CustomerShippingDetails {
@@ -10,6 +10,21 @@ pub struct CustomerShippingDetails {
     pub phone: String,
 }
 
+#[derive(Default, Serialize)]
+pub struct InvoiceSettingsParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_payment_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<&'a str>,
+}
+
+/// The default settings of the invoices of a customer.
+#[derive(Debug, Deserialize)]
+pub struct InvoiceSettings {
+    pub default_payment_method: Option<Expandable<PaymentMethod>>,
+    pub footer: Option<String>,
+}
+
 #[derive(Serialize)]
 #[serde(untagged)]
 pub enum CustomerSource<'a> {
@@ -33,13 +48,84 @@ pub struct CustomerParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_settings: Option<InvoiceSettingsParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<&'a str>, // NOTE: Only for create
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub shipping: Option<CustomerShippingDetails>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<CustomerSource<'a>>,
 }
 
+/// The set of parameters that can be used when listing customers.
+///
+/// For more details see https://stripe.com/docs/api#list_customers.
+#[derive(Default, Serialize)]
+pub struct CustomerListParams<'a> {
+    #[serde(flatten)]
+    pub list: ListParams<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when listing a customer's sources.
+///
+/// For more details see https://stripe.com/docs/api#list_cards and https://stripe.com/docs/api#customer_list_bank_accounts.
+#[derive(Default, Serialize)]
+pub struct SourceListParams<'a> {
+    #[serde(flatten)]
+    pub list: ListParams<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object: Option<&'a str>, // (card, bank_account)
+}
+
+#[derive(Serialize)]
+struct AttachSourceParams<'a> {
+    source: CustomerSource<'a>,
+}
+
+/// The set of parameters that can be used when updating one of a customer's sources.
+///
+/// For more details see https://stripe.com/docs/api#update_card and https://stripe.com/docs/api#customer_update_bank_account.
+#[derive(Default, Serialize)]
+pub struct SourceUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_type: Option<&'a str>, // (individual, company)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_city: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_country: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line1: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line2: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_state: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_zip: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_month: Option<&'a str>, // eg. "12"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_year: Option<&'a str>, // eg. "17" or 2017"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when verifying a customer's bank account.
+///
+/// For more details see https://stripe.com/docs/api#customer_verify_bank_account.
+#[derive(Default, Serialize)]
+pub struct BankAccountVerifyParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amounts: Option<Vec<u64>>, // the two micro-deposits, in cents
+}
+
 /// The resource representing a Stripe customer.
 ///
 /// For more details see https://stripe.com/docs/api#customers.
@@ -50,11 +136,12 @@ pub struct Customer {
     pub business_vat_id: Option<String>,
     pub created: u64,
     pub currency: Option<Currency>,
-    pub default_source: Option<String>,
+    pub default_source: Option<Expandable<Source>>,
     pub delinquent: bool,
     pub desc: Option<String>,
     pub discount: Option<Discount>,
-    pub email: String,
+    pub email: Option<String>,
+    pub invoice_settings: Option<InvoiceSettings>, // NOTE: Missing in older API versions
     pub livemode: bool,
     pub metadata: Metadata,
     pub shipping: Option<CustomerShippingDetails>,
@@ -84,10 +171,82 @@ impl Customer {
         client.post(&format!("/customers/{}", customer_id), params)
     }
 
+    /// Lists all customers, optionally filtered by email or creation date.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_customers.
+    pub fn list(client: &Client, params: CustomerListParams) -> Result<List<Customer>, Error> {
+        client.get_list("/customers", &params)
+    }
+
     /// Deletes a customer.
     ///
     /// For more details see https://stripe.com/docs/api#delete_customer.
     pub fn delete(client: &Client, customer_id: &str) -> Result<Deleted, Error> {
         client.delete(&format!("/customers/{}", customer_id))
     }
+
+    /// Removes the discount applied to a customer.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_discount.
+    pub fn delete_discount(client: &Client, customer_id: &str) -> Result<Deleted, Error> {
+        client.delete(&format!("/customers/{}/discount", customer_id))
+    }
+
+    /// Lists the sources (eg. cards and bank accounts) of a customer.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_cards.
+    pub fn list_sources(client: &Client, customer_id: &str, params: SourceListParams) -> Result<List<Source>, Error> {
+        client.get_list(&format!("/customers/{}/sources", customer_id), &params)
+    }
+
+    /// Attaches a source (eg. a card or token) to a customer.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_card.
+    pub fn attach_source(client: &Client, customer_id: &str, source: CustomerSource) -> Result<Source, Error> {
+        client.post(&format!("/customers/{}/sources", customer_id), AttachSourceParams { source })
+    }
+
+    /// Retrieves the details of one of a customer's sources.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_card.
+    pub fn retrieve_source(client: &Client, customer_id: &str, source_id: &str) -> Result<Source, Error> {
+        client.get(&format!("/customers/{}/sources/{}", customer_id, source_id))
+    }
+
+    /// Updates one of a customer's sources.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_card.
+    pub fn update_source(
+        client: &Client,
+        customer_id: &str,
+        source_id: &str,
+        params: SourceUpdateParams,
+    ) -> Result<Source, Error> {
+        client.post(&format!("/customers/{}/sources/{}", customer_id, source_id), params)
+    }
+
+    /// Detaches a source from a customer.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_card.
+    pub fn detach_source(client: &Client, customer_id: &str, source_id: &str) -> Result<Deleted, Error> {
+        client.delete(&format!("/customers/{}/sources/{}", customer_id, source_id))
+    }
+
+    /// Verifies a customer's bank account with the amounts of the two micro-deposits sent to it.
+    ///
+    /// For more details see https://stripe.com/docs/api#customer_verify_bank_account.
+    pub fn verify_bank_account(
+        client: &Client,
+        customer_id: &str,
+        bank_account_id: &str,
+        params: BankAccountVerifyParams,
+    ) -> Result<BankAccount, Error> {
+        client.post(&format!("/customers/{}/sources/{}/verify", customer_id, bank_account_id), params)
+    }
+}
+
+impl Identifiable for Customer {
+    fn id(&self) -> &str {
+        &self.id
+    }
 }
@@ -1,5 +1,7 @@
 #[derive(Deserialize)]
 pub struct Deleted {
     pub deleted: bool,
+    #[serde(default)]
+    // NOTE: Missing in response to deleting a discount
     pub id: String,
 }
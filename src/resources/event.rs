@@ -1,38 +1,356 @@
-use resources::{Charge, Invoice, Subscription};
+use client::Client;
+use error::Error;
+use params::{Identifiable, List, ListParams, Timestamp};
+use resources::{BankAccount, Card, Charge, Coupon, Customer, Discount, Invoice, InvoiceItem, PaymentIntent, PaymentMethod, Plan,
+                Price, Product, Refund, SetupIntent, Subscription, SubscriptionItem};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::{DeserializeOwned, Error as DeError};
+use serde_json as json;
+use std::fmt;
 
-#[derive(Debug, PartialEq, Deserialize, Serialize)]
-pub enum EventType {
-    #[serde(rename = "charge.succeeded")]
-    ChargeSucceeded,
-    #[serde(rename = "customer.subscription.created")]
-    CustomerSubscriptionCreated,
-    #[serde(rename = "invoice.created")]
-    InvoiceCreated,
-    #[serde(rename = "invoice.updated")]
-    InvoiceUpdated,
+macro_rules! event_types {
+    ($($variant:ident => $name:tt,)*) => {
+        /// The type of an event, which names the resource and the change that triggered it.
+        ///
+        /// For more details see https://stripe.com/docs/api#event_types.
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum EventType {
+            $($variant,)*
+            /// An event type which isn't known to this version of the crate.
+            Unknown(String),
+        }
+
+        impl EventType {
+            pub fn as_str(&self) -> &str {
+                match *self {
+                    $(EventType::$variant => $name,)*
+                    EventType::Unknown(ref name) => name,
+                }
+            }
+        }
+
+        impl<'a> From<&'a str> for EventType {
+            fn from(name: &'a str) -> EventType {
+                match name {
+                    $($name => EventType::$variant,)*
+                    _ => EventType::Unknown(name.to_string()),
+                }
+            }
+        }
+    }
+}
+
+event_types! {
+    AccountUpdated => "account.updated",
+    AccountApplicationAuthorized => "account.application.authorized",
+    AccountApplicationDeauthorized => "account.application.deauthorized",
+    AccountExternalAccountCreated => "account.external_account.created",
+    AccountExternalAccountDeleted => "account.external_account.deleted",
+    AccountExternalAccountUpdated => "account.external_account.updated",
+    ApplicationFeeCreated => "application_fee.created",
+    ApplicationFeeRefunded => "application_fee.refunded",
+    ApplicationFeeRefundUpdated => "application_fee.refund.updated",
+    BalanceAvailable => "balance.available",
+    CapabilityUpdated => "capability.updated",
+    ChargeCaptured => "charge.captured",
+    ChargeExpired => "charge.expired",
+    ChargeFailed => "charge.failed",
+    ChargePending => "charge.pending",
+    ChargeRefunded => "charge.refunded",
+    ChargeSucceeded => "charge.succeeded",
+    ChargeUpdated => "charge.updated",
+    ChargeDisputeClosed => "charge.dispute.closed",
+    ChargeDisputeCreated => "charge.dispute.created",
+    ChargeDisputeFundsReinstated => "charge.dispute.funds_reinstated",
+    ChargeDisputeFundsWithdrawn => "charge.dispute.funds_withdrawn",
+    ChargeDisputeUpdated => "charge.dispute.updated",
+    ChargeRefundUpdated => "charge.refund.updated",
+    CheckoutSessionAsyncPaymentFailed => "checkout.session.async_payment_failed",
+    CheckoutSessionAsyncPaymentSucceeded => "checkout.session.async_payment_succeeded",
+    CheckoutSessionCompleted => "checkout.session.completed",
+    CouponCreated => "coupon.created",
+    CouponDeleted => "coupon.deleted",
+    CouponUpdated => "coupon.updated",
+    CreditNoteCreated => "credit_note.created",
+    CreditNoteUpdated => "credit_note.updated",
+    CreditNoteVoided => "credit_note.voided",
+    CustomerCreated => "customer.created",
+    CustomerDeleted => "customer.deleted",
+    CustomerUpdated => "customer.updated",
+    CustomerDiscountCreated => "customer.discount.created",
+    CustomerDiscountDeleted => "customer.discount.deleted",
+    CustomerDiscountUpdated => "customer.discount.updated",
+    CustomerSourceCreated => "customer.source.created",
+    CustomerSourceDeleted => "customer.source.deleted",
+    CustomerSourceExpiring => "customer.source.expiring",
+    CustomerSourceUpdated => "customer.source.updated",
+    CustomerSubscriptionCreated => "customer.subscription.created",
+    CustomerSubscriptionDeleted => "customer.subscription.deleted",
+    CustomerSubscriptionPendingUpdateApplied => "customer.subscription.pending_update_applied",
+    CustomerSubscriptionPendingUpdateExpired => "customer.subscription.pending_update_expired",
+    CustomerSubscriptionTrialWillEnd => "customer.subscription.trial_will_end",
+    CustomerSubscriptionUpdated => "customer.subscription.updated",
+    CustomerTaxIdCreated => "customer.tax_id.created",
+    CustomerTaxIdDeleted => "customer.tax_id.deleted",
+    CustomerTaxIdUpdated => "customer.tax_id.updated",
+    FileCreated => "file.created",
+    InvoiceCreated => "invoice.created",
+    InvoiceDeleted => "invoice.deleted",
+    InvoiceFinalized => "invoice.finalized",
+    InvoiceMarkedUncollectible => "invoice.marked_uncollectible",
+    InvoicePaid => "invoice.paid",
+    InvoicePaymentActionRequired => "invoice.payment_action_required",
+    InvoicePaymentFailed => "invoice.payment_failed",
+    InvoicePaymentSucceeded => "invoice.payment_succeeded",
+    InvoiceSent => "invoice.sent",
+    InvoiceUpcoming => "invoice.upcoming",
+    InvoiceUpdated => "invoice.updated",
+    InvoiceVoided => "invoice.voided",
+    InvoiceItemCreated => "invoiceitem.created",
+    InvoiceItemDeleted => "invoiceitem.deleted",
+    InvoiceItemUpdated => "invoiceitem.updated",
+    IssuingAuthorizationCreated => "issuing_authorization.created",
+    IssuingAuthorizationRequest => "issuing_authorization.request",
+    IssuingAuthorizationUpdated => "issuing_authorization.updated",
+    IssuingCardCreated => "issuing_card.created",
+    IssuingCardUpdated => "issuing_card.updated",
+    IssuingCardholderCreated => "issuing_cardholder.created",
+    IssuingCardholderUpdated => "issuing_cardholder.updated",
+    IssuingTransactionCreated => "issuing_transaction.created",
+    IssuingTransactionUpdated => "issuing_transaction.updated",
+    MandateUpdated => "mandate.updated",
+    OrderCreated => "order.created",
+    OrderPaymentFailed => "order.payment_failed",
+    OrderPaymentSucceeded => "order.payment_succeeded",
+    OrderUpdated => "order.updated",
+    OrderReturnCreated => "order_return.created",
+    PaymentIntentAmountCapturableUpdated => "payment_intent.amount_capturable_updated",
+    PaymentIntentCanceled => "payment_intent.canceled",
+    PaymentIntentCreated => "payment_intent.created",
+    PaymentIntentPaymentFailed => "payment_intent.payment_failed",
+    PaymentIntentProcessing => "payment_intent.processing",
+    PaymentIntentRequiresAction => "payment_intent.requires_action",
+    PaymentIntentSucceeded => "payment_intent.succeeded",
+    PaymentMethodAttached => "payment_method.attached",
+    PaymentMethodAutomaticallyUpdated => "payment_method.automatically_updated",
+    PaymentMethodDetached => "payment_method.detached",
+    PaymentMethodUpdated => "payment_method.updated",
+    PayoutCanceled => "payout.canceled",
+    PayoutCreated => "payout.created",
+    PayoutFailed => "payout.failed",
+    PayoutPaid => "payout.paid",
+    PayoutUpdated => "payout.updated",
+    PersonCreated => "person.created",
+    PersonDeleted => "person.deleted",
+    PersonUpdated => "person.updated",
+    PlanCreated => "plan.created",
+    PlanDeleted => "plan.deleted",
+    PlanUpdated => "plan.updated",
+    PriceCreated => "price.created",
+    PriceDeleted => "price.deleted",
+    PriceUpdated => "price.updated",
+    ProductCreated => "product.created",
+    ProductDeleted => "product.deleted",
+    ProductUpdated => "product.updated",
+    RadarEarlyFraudWarningCreated => "radar.early_fraud_warning.created",
+    RadarEarlyFraudWarningUpdated => "radar.early_fraud_warning.updated",
+    RecipientCreated => "recipient.created",
+    RecipientDeleted => "recipient.deleted",
+    RecipientUpdated => "recipient.updated",
+    ReportingReportRunFailed => "reporting.report_run.failed",
+    ReportingReportRunSucceeded => "reporting.report_run.succeeded",
+    ReportingReportTypeUpdated => "reporting.report_type.updated",
+    ReviewClosed => "review.closed",
+    ReviewOpened => "review.opened",
+    SetupIntentCanceled => "setup_intent.canceled",
+    SetupIntentCreated => "setup_intent.created",
+    SetupIntentRequiresAction => "setup_intent.requires_action",
+    SetupIntentSetupFailed => "setup_intent.setup_failed",
+    SetupIntentSucceeded => "setup_intent.succeeded",
+    SigmaScheduledQueryRunCreated => "sigma.scheduled_query_run.created",
+    SkuCreated => "sku.created",
+    SkuDeleted => "sku.deleted",
+    SkuUpdated => "sku.updated",
+    SourceCanceled => "source.canceled",
+    SourceChargeable => "source.chargeable",
+    SourceFailed => "source.failed",
+    SourceMandateNotification => "source.mandate_notification",
+    SourceRefundAttributesRequired => "source.refund_attributes_required",
+    SourceTransactionCreated => "source.transaction.created",
+    SourceTransactionUpdated => "source.transaction.updated",
+    SubscriptionScheduleAborted => "subscription_schedule.aborted",
+    SubscriptionScheduleCanceled => "subscription_schedule.canceled",
+    SubscriptionScheduleCompleted => "subscription_schedule.completed",
+    SubscriptionScheduleCreated => "subscription_schedule.created",
+    SubscriptionScheduleExpiring => "subscription_schedule.expiring",
+    SubscriptionScheduleReleased => "subscription_schedule.released",
+    SubscriptionScheduleUpdated => "subscription_schedule.updated",
+    TaxRateCreated => "tax_rate.created",
+    TaxRateUpdated => "tax_rate.updated",
+    TopupCanceled => "topup.canceled",
+    TopupCreated => "topup.created",
+    TopupFailed => "topup.failed",
+    TopupReversed => "topup.reversed",
+    TopupSucceeded => "topup.succeeded",
+    TransferCreated => "transfer.created",
+    TransferReversed => "transfer.reversed",
+    TransferUpdated => "transfer.updated",
+    Ping => "ping",
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for EventType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for EventType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(EventType::from(name.as_str()))
+    }
+}
+
+/// The set of parameters that can be used when listing events.
+///
+/// For more details see https://stripe.com/docs/api#list_events.
+#[derive(Default, Serialize)]
+pub struct EventListParams<'a> {
+    #[serde(flatten)]
+    pub list: ListParams<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_success: Option<bool>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_type: Option<EventType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub types: Option<Vec<EventType>>,
+}
+
+/// The API request that triggered an event, if any.
+#[derive(Debug, Deserialize)]
+pub struct EventRequest {
+    pub id: Option<String>,
+    pub idempotency_key: Option<String>,
 }
 
+/// The resource representing a Stripe event.
+///
+/// For more details see https://stripe.com/docs/api#events.
 #[derive(Debug, Deserialize)]
 pub struct Event {
+    pub id: String,
+    pub api_version: Option<String>,
+    pub created: Timestamp,
+    pub data: EventData,
+    pub livemode: bool,
+    pub pending_webhooks: u64,
+    pub request: Option<EventRequest>,
     #[serde(rename = "type")]
     pub event_type: EventType,
-    pub data: EventData,
-    // ...
 }
 
 #[derive(Debug, Deserialize)]
 pub struct EventData {
     pub object: EventObject,
-    // previous_attributes: ...
+    #[serde(default)]
+    pub previous_attributes: Option<json::Value>, // NOTE: Only set for "*.updated" events
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(tag = "object")]
+impl EventData {
+    /// Deserializes the previous values of the attributes that changed in an "*.updated" event.
+    ///
+    /// Only the attributes that changed are present, so `T` should only have optional fields,
+    /// eg. `struct ChargeChanges { amount: Option<u64>, description: Option<String> }`.
+    pub fn previous_attributes_as<T: DeserializeOwned>(&self) -> Result<Option<T>, Error> {
+        match self.previous_attributes {
+            Some(ref attributes) => Ok(Some(json::from_value(attributes.clone())?)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl Event {
+    /// Retrieves the details of an event.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_event.
+    pub fn retrieve(client: &Client, event_id: &str) -> Result<Event, Error> {
+        client.get(&format!("/events/{}", event_id))
+    }
+
+    /// Lists the events from the last 30 days, most recent first.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_events.
+    pub fn list(client: &Client, params: EventListParams) -> Result<List<Event>, Error> {
+        client.get_list("/events", &params)
+    }
+}
+
+/// The object an event is about, determined by its "object" field.
+///
+/// The resources are boxed, since they're much larger than an untyped `Unknown` object.
+#[derive(Debug)]
 pub enum EventObject {
-    #[serde(rename = "charge")]
-    Charge(Charge),
-    #[serde(rename = "invoice")]
-    Invoice(Invoice),
-    #[serde(rename = "subscription")]
-    Subscription(Subscription),
+    BankAccount(Box<BankAccount>),
+    Card(Box<Card>),
+    Charge(Box<Charge>),
+    Coupon(Box<Coupon>),
+    Customer(Box<Customer>),
+    Discount(Box<Discount>),
+    Invoice(Box<Invoice>),
+    InvoiceItem(Box<InvoiceItem>),
+    PaymentIntent(Box<PaymentIntent>),
+    PaymentMethod(Box<PaymentMethod>),
+    Plan(Box<Plan>),
+    Price(Box<Price>),
+    Product(Box<Product>),
+    Refund(Box<Refund>),
+    SetupIntent(Box<SetupIntent>),
+    Subscription(Box<Subscription>),
+    SubscriptionItem(Box<SubscriptionItem>),
+    /// An object which isn't modeled by this version of the crate (eg. "source" or "payout").
+    ///
+    /// NOTE: The "source" objects of the Sources API (eg. for 3D Secure or SEPA debits) aren't
+    ///       modeled yet, since `Source` only covers the cards and bank accounts of customers.
+    Unknown(json::Value),
+}
+
+impl<'de> Deserialize<'de> for EventObject {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = json::Value::deserialize(deserializer)?;
+        let object = value.get("object").and_then(|object| object.as_str()).map(|object| object.to_string());
+        let result = match object.as_deref() {
+            Some("bank_account") => json::from_value(value).map(EventObject::BankAccount),
+            Some("card") => json::from_value(value).map(EventObject::Card),
+            Some("charge") => json::from_value(value).map(EventObject::Charge),
+            Some("coupon") => json::from_value(value).map(EventObject::Coupon),
+            Some("customer") => json::from_value(value).map(EventObject::Customer),
+            Some("discount") => json::from_value(value).map(EventObject::Discount),
+            Some("invoice") => json::from_value(value).map(EventObject::Invoice),
+            Some("invoiceitem") => json::from_value(value).map(EventObject::InvoiceItem),
+            Some("payment_intent") => json::from_value(value).map(EventObject::PaymentIntent),
+            Some("payment_method") => json::from_value(value).map(EventObject::PaymentMethod),
+            Some("plan") => json::from_value(value).map(EventObject::Plan),
+            Some("price") => json::from_value(value).map(EventObject::Price),
+            Some("product") => json::from_value(value).map(EventObject::Product),
+            Some("refund") => json::from_value(value).map(EventObject::Refund),
+            Some("setup_intent") => json::from_value(value).map(EventObject::SetupIntent),
+            Some("subscription") => json::from_value(value).map(EventObject::Subscription),
+            Some("subscription_item") => json::from_value(value).map(EventObject::SubscriptionItem),
+            _ => Ok(EventObject::Unknown(value)),
+        };
+        result.map_err(D::Error::custom)
+    }
+}
+
+impl Identifiable for Event {
+    fn id(&self) -> &str {
+        &self.id
+    }
 }
@@ -1,8 +1,9 @@
 use error::Error;
 use client::Client;
-use params::{List, Metadata, Timestamp};
-use resources::{Currency, Discount, Plan};
-use serde_qs as qs;
+use params::{Expandable, Identifiable, List, ListParams, Metadata, RangeQuery, Timestamp};
+use resources::{Charge, Currency, Customer, Deleted, Discount, Plan, Subscription};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
 
 /// The set of parameters that can be used when creating or updating an invoice.
 ///
@@ -12,6 +13,8 @@ pub struct InvoiceParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_fee: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_advance: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub customer: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<&'a str>,
@@ -22,12 +25,39 @@ pub struct InvoiceParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tax_percent: Option<f64>,
 
+    // NOTE: Only supported by API versions before 2018-11-08, use `Invoice::void` and
+    //       `Invoice::mark_uncollectible` instead.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub closed: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub forgiven: Option<bool>,
 }
 
+/// The set of parameters that can be used when finalizing a draft invoice.
+///
+/// For more details see https://stripe.com/docs/api/invoices/finalize.
+#[derive(Default, Serialize)]
+pub struct InvoiceFinalizeParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_advance: Option<bool>,
+}
+
+/// The set of parameters that can be used when paying an invoice.
+///
+/// For more details see https://stripe.com/docs/api/invoices/pay.
+#[derive(Default, Serialize)]
+pub struct InvoicePayParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub off_session: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paid_out_of_band: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when creating or updating an invoice item.
+///
+/// For more details see https://stripe.com/docs/api#create_invoiceitem and https://stripe.com/docs/api#update_invoiceitem.
 #[derive(Default, Serialize)]
 pub struct InvoiceItemParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -43,21 +73,76 @@ pub struct InvoiceItemParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub invoice: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<bool>,
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when listing invoice items.
+///
+/// For more details see https://stripe.com/docs/api#list_invoiceitems.
+#[derive(Default, Serialize)]
+pub struct InvoiceItemListParams<'a> {
+    #[serde(flatten)]
+    pub list: ListParams<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending: Option<bool>,
+}
+
+/// The set of parameters that can be used when listing the line items of an invoice.
+///
+/// For more details see https://stripe.com/docs/api#invoice_lines.
+#[derive(Default, Serialize)]
+pub struct InvoiceListLinesParams<'a> {
+    #[serde(flatten)]
+    pub list: ListParams<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>, // NOTE: Only for the "upcoming" invoice
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub subscription: Option<bool>,
+    pub subscription: Option<&'a str>, // NOTE: Only for the "upcoming" invoice
 }
 
-/*
-#[derive(Serialize)]
-pub struct InvoiceListLinesParams {
-    #[serde(skip_serializing_if = "Option::is_none")] pub limit: Option<u64>,
-    #[serde(skip_serializing_if = "Option::is_none")] pub ending_before: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")] pub starting_after: Option<String>,
+/// A change to the items of a subscription, previewed by a customer's upcoming invoice.
+///
+/// Set `id` to change or remove (with `deleted`) an existing item, otherwise a new item is added.
+#[derive(Default, Serialize)]
+pub struct InvoiceUpcomingItemParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clear_usage: Option<bool>, // NOTE: Only for deleted items of metered plans
+}
 
-    ..
+/// The set of parameters that can be used when retrieving a customer's upcoming invoice.
+///
+/// The `subscription_*` params preview the invoice as if the subscription was updated with them.
+/// For more details see https://stripe.com/docs/api#upcoming_invoice.
+#[derive(Default, Serialize)]
+pub struct InvoiceUpcomingParams<'a> {
+    pub customer: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_items: Option<Vec<InvoiceUpcomingItemParams<'a>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_prorate: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_proration_date: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_trial_end: Option<Timestamp>,
 }
-*/
 
 /// Period is a structure representing a start and end dates.
 #[derive(Debug, Deserialize)]
@@ -74,8 +159,11 @@ pub struct InvoiceItem {
     pub id: String,
     pub amount: i64,
     pub currency: Currency,
+    pub customer: Option<String>, // NOTE: Missing in invoice line items
+    pub date: Option<Timestamp>, // NOTE: Missing in invoice line items
     pub description: Option<String>,
     pub discountable: bool,
+    pub invoice: Option<String>, // NOTE: Missing in invoice line items
     pub livemode: bool,
     pub metadata: Metadata,
     pub period: Period,
@@ -90,24 +178,90 @@ pub struct InvoiceItem {
     pub item_type: String, // (invoiceitem, subscription)
 }
 
+/// The status of an invoice.
+///
+/// For more details see https://stripe.com/docs/billing/invoices/workflow.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InvoiceStatus {
+    Draft,
+    Open,
+    Paid,
+    Uncollectible,
+    Void,
+    /// A status which isn't known to this version of the crate.
+    Unknown(String),
+}
+
+impl InvoiceStatus {
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InvoiceStatus::Draft => "draft",
+            InvoiceStatus::Open => "open",
+            InvoiceStatus::Paid => "paid",
+            InvoiceStatus::Uncollectible => "uncollectible",
+            InvoiceStatus::Void => "void",
+            InvoiceStatus::Unknown(ref status) => status,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InvoiceStatus {
+    fn from(status: &'a str) -> InvoiceStatus {
+        match status {
+            "draft" => InvoiceStatus::Draft,
+            "open" => InvoiceStatus::Open,
+            "paid" => InvoiceStatus::Paid,
+            "uncollectible" => InvoiceStatus::Uncollectible,
+            "void" => InvoiceStatus::Void,
+            _ => InvoiceStatus::Unknown(status.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for InvoiceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for InvoiceStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for InvoiceStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let status = String::deserialize(deserializer)?;
+        Ok(InvoiceStatus::from(status.as_str()))
+    }
+}
+
 /// The resource representing a Stripe invoice.
 ///
 /// For more details see https://stripe.com/docs/api#invoice_object.
 #[derive(Debug, Deserialize)]
 pub struct Invoice {
+    #[serde(default)]
+    // NOTE: Missing in the upcoming invoice
     pub id: String,
     pub amount_due: u64,
     pub application_fee: Option<u64>,
     pub attempt_count: u64,
     pub attempted: bool,
-    pub charge: Option<String>,
+    pub auto_advance: Option<bool>,
+    pub charge: Option<Expandable<Charge>>,
+    #[serde(default)]
+    // NOTE: Missing since API version 2018-11-08, see `status`
     pub closed: bool,
     pub currency: Currency,
-    pub customer: String,
+    pub customer: Expandable<Customer>,
     pub date: Timestamp,
     pub description: Option<String>,
     pub discount: Option<Discount>,
     pub ending_balance: Option<i64>,
+    #[serde(default)]
+    // NOTE: Missing since API version 2018-11-08, see `status`
     pub forgiven: bool,
     pub lines: List<InvoiceItem>,
     pub livemode: bool,
@@ -118,8 +272,9 @@ pub struct Invoice {
     pub period_start: Timestamp,
     pub receipt_number: Option<String>,
     pub starting_balance: i64,
+    pub status: Option<InvoiceStatus>, // NOTE: Missing when the API version is overridden to one before 2018-11-08
     pub statment_descriptor: Option<String>,
-    pub subscription: Option<String>,
+    pub subscription: Option<Expandable<Subscription>>,
     pub subscription_proration_date: Option<Timestamp>,
     pub subtotal: i64,
     pub tax: Option<i64>,
@@ -128,12 +283,23 @@ pub struct Invoice {
     pub webhooks_delivered_at: Option<Timestamp>,
 }
 
+/// The set of parameters that can be used when listing invoices.
+///
+/// For more details see https://stripe.com/docs/api#list_invoices.
 #[derive(Default, Serialize)]
 pub struct InvoiceListParams<'a> {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<u64>,
+    #[serde(flatten)]
+    pub list: ListParams<'a>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<InvoiceStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription: Option<&'a str>,
 }
 
 impl Invoice {
@@ -151,21 +317,65 @@ impl Invoice {
         client.get(&format!("/invoices/{}", invoice_id))
     }
 
-    // TODO: Implement InvoiceListLinesParams
-    // pub fn get_lines(client: &Client, invoice_id: &str, params: InvoiceListLinesParams) -> Result<List<InvoiceItem>, Error> {
-    //     client.get(&format!("/invoices/{}/lines", invoice_id))
-    // }
+    /// Lists the line items of an invoice.
+    ///
+    /// The lines of a customer's upcoming invoice can be listed with the "upcoming" invoice id.
+    /// For more details see https://stripe.com/docs/api#invoice_lines.
+    pub fn lines(
+        client: &Client,
+        invoice_id: &str,
+        params: InvoiceListLinesParams,
+    ) -> Result<List<InvoiceItem>, Error> {
+        client.get_list(&format!("/invoices/{}/lines", invoice_id), &params)
+    }
 
-    // TODO: Implement InvoiceUpcomingParams
-    // pub fn get_upcoming(client: &Client, params: InvoiceUpcomingParams) -> Result<Invoice, Error> {
-    //     client.get(&format!("/invoices/upcoming?customer={}", invoice_id))
-    // }
+    /// Retrieves a preview of a customer's upcoming invoice, which has no id.
+    ///
+    /// For more details see https://stripe.com/docs/api#upcoming_invoice.
+    pub fn upcoming(client: &Client, params: InvoiceUpcomingParams) -> Result<Invoice, Error> {
+        client.get_query("/invoices/upcoming", &params)
+    }
 
-    /// Pays an invoice.
+    /// Pays an open invoice, or marks it as paid outside of Stripe with `paid_out_of_band`.
     ///
     /// For more details see https://stripe.com/docs/api#pay_invoice.
-    pub fn pay(client: &Client, invoice_id: &str) -> Result<Invoice, Error> {
-        client.post_empty(&format!("/invoices/{}/pay", invoice_id))
+    pub fn pay(client: &Client, invoice_id: &str, params: InvoicePayParams) -> Result<Invoice, Error> {
+        client.post(&format!("/invoices/{}/pay", invoice_id), &params)
+    }
+
+    /// Finalizes a draft invoice, which moves it to the open status.
+    ///
+    /// For more details see https://stripe.com/docs/api/invoices/finalize.
+    pub fn finalize(client: &Client, invoice_id: &str, params: InvoiceFinalizeParams) -> Result<Invoice, Error> {
+        client.post(&format!("/invoices/{}/finalize", invoice_id), &params)
+    }
+
+    /// Sends an open invoice to the customer for manual payment.
+    ///
+    /// For more details see https://stripe.com/docs/api/invoices/send.
+    pub fn send_invoice(client: &Client, invoice_id: &str) -> Result<Invoice, Error> {
+        client.post_empty(&format!("/invoices/{}/send", invoice_id))
+    }
+
+    /// Voids an open invoice, which can no longer be paid afterwards.
+    ///
+    /// For more details see https://stripe.com/docs/api/invoices/void.
+    pub fn void(client: &Client, invoice_id: &str) -> Result<Invoice, Error> {
+        client.post_empty(&format!("/invoices/{}/void", invoice_id))
+    }
+
+    /// Marks an open invoice as uncollectible.
+    ///
+    /// For more details see https://stripe.com/docs/api/invoices/mark_uncollectible.
+    pub fn mark_uncollectible(client: &Client, invoice_id: &str) -> Result<Invoice, Error> {
+        client.post_empty(&format!("/invoices/{}/mark_uncollectible", invoice_id))
+    }
+
+    /// Deletes a draft invoice, other invoices must be voided instead.
+    ///
+    /// For more details see https://stripe.com/docs/api/invoices/delete.
+    pub fn delete(client: &Client, invoice_id: &str) -> Result<Deleted, Error> {
+        client.delete(&format!("/invoices/{}", invoice_id))
     }
 
     /// Updates an invoice.
@@ -175,11 +385,11 @@ impl Invoice {
         client.post(&format!("/invoices/{}", invoice_id), &params)
     }
 
-    /// Lists all invoices with optional customer_id and limit params
+    /// Lists all invoices, optionally filtered by customer, subscription or date.
     ///
     /// For more details see https://stripe.com/docs/api#list_invoices.
     pub fn list(client: &Client, params: InvoiceListParams) -> Result<List<Invoice>, Error> {
-        client.get(&format!("/invoices?{}", qs::to_string(&params)?))
+        client.get_list("/invoices", &params)
     }
 }
 
@@ -190,4 +400,44 @@ impl InvoiceItem {
     pub fn create(client: &Client, params: InvoiceItemParams) -> Result<InvoiceItem, Error> {
         client.post(&format!("/invoiceitems"), &params)
     }
+
+    /// Retrieves the details of an invoice item.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_invoiceitem.
+    pub fn retrieve(client: &Client, invoice_item_id: &str) -> Result<InvoiceItem, Error> {
+        client.get(&format!("/invoiceitems/{}", invoice_item_id))
+    }
+
+    /// Updates an invoice item, which is only possible until its invoice is closed.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_invoiceitem.
+    pub fn update(client: &Client, invoice_item_id: &str, params: InvoiceItemParams) -> Result<InvoiceItem, Error> {
+        client.post(&format!("/invoiceitems/{}", invoice_item_id), &params)
+    }
+
+    /// Deletes an invoice item which isn't attached to a closed invoice.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_invoiceitem.
+    pub fn delete(client: &Client, invoice_item_id: &str) -> Result<Deleted, Error> {
+        client.delete(&format!("/invoiceitems/{}", invoice_item_id))
+    }
+
+    /// Lists all invoice items, optionally filtered by customer, invoice or creation date.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_invoiceitems.
+    pub fn list(client: &Client, params: InvoiceItemListParams) -> Result<List<InvoiceItem>, Error> {
+        client.get_list("/invoiceitems", &params)
+    }
+}
+
+impl Identifiable for Invoice {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl Identifiable for InvoiceItem {
+    fn id(&self) -> &str {
+        &self.id
+    }
 }
@@ -1,4 +1,5 @@
 mod address;
+mod bank_account;
 mod card;
 mod charge;
 mod coupon;
@@ -8,12 +9,19 @@ mod deleted;
 mod discount;
 mod event;
 mod invoices;
+mod payment_intent;
+mod payment_method;
 mod plan;
+mod price;
+mod product;
 mod refund;
+mod setup_intent;
 mod source;
 mod subscription;
+mod usage_record;
 
 pub use resources::address::*;
+pub use resources::bank_account::*;
 pub use resources::card::*;
 pub use resources::charge::*;
 pub use resources::coupon::*;
@@ -23,7 +31,13 @@ pub use resources::deleted::*;
 pub use resources::discount::*;
 pub use resources::event::*;
 pub use resources::invoices::*;
+pub use resources::payment_intent::*;
+pub use resources::payment_method::*;
 pub use resources::plan::*;
+pub use resources::price::*;
+pub use resources::product::*;
 pub use resources::refund::*;
+pub use resources::setup_intent::*;
 pub use resources::source::*;
 pub use resources::subscription::*;
+pub use resources::usage_record::*;
@@ -0,0 +1,394 @@
+use client::Client;
+use error::Error;
+use params::{Expandable, Identifiable, List, ListParams, Metadata, Timestamp};
+use resources::{Charge, Currency, Customer, Invoice, PaymentMethod};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json as json;
+use std::fmt;
+
+/// Whether the funds of a payment intent are captured automatically or with `PaymentIntent::capture`.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+pub enum CaptureMethod {
+    #[serde(rename = "automatic")]
+    Automatic,
+    #[serde(rename = "manual")]
+    Manual,
+}
+
+/// Whether a payment intent can be confirmed with its client secret or only with a secret key.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+pub enum ConfirmationMethod {
+    #[serde(rename = "automatic")]
+    Automatic,
+    #[serde(rename = "manual")]
+    Manual,
+
+    // NOTE: Only returned by API versions before 2019-02-11, instead of `automatic` and `manual`
+    #[serde(rename = "publishable")]
+    Publishable,
+    #[serde(rename = "secret")]
+    Secret,
+}
+
+/// The status of a payment intent.
+///
+/// For more details see https://stripe.com/docs/payments/intents#intent-statuses.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PaymentIntentStatus {
+    Canceled,
+    Processing,
+    RequiresAction,
+    RequiresCapture,
+    RequiresConfirmation,
+    RequiresPaymentMethod,
+    Succeeded,
+    /// Only returned by API versions before 2019-02-11, instead of `RequiresPaymentMethod`.
+    RequiresSource,
+    /// Only returned by API versions before 2019-02-11, instead of `RequiresAction`.
+    RequiresSourceAction,
+    /// A status which isn't known to this version of the crate.
+    Unknown(String),
+}
+
+impl PaymentIntentStatus {
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PaymentIntentStatus::Canceled => "canceled",
+            PaymentIntentStatus::Processing => "processing",
+            PaymentIntentStatus::RequiresAction => "requires_action",
+            PaymentIntentStatus::RequiresCapture => "requires_capture",
+            PaymentIntentStatus::RequiresConfirmation => "requires_confirmation",
+            PaymentIntentStatus::RequiresPaymentMethod => "requires_payment_method",
+            PaymentIntentStatus::Succeeded => "succeeded",
+            PaymentIntentStatus::RequiresSource => "requires_source",
+            PaymentIntentStatus::RequiresSourceAction => "requires_source_action",
+            PaymentIntentStatus::Unknown(ref status) => status,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PaymentIntentStatus {
+    fn from(status: &'a str) -> PaymentIntentStatus {
+        match status {
+            "canceled" => PaymentIntentStatus::Canceled,
+            "processing" => PaymentIntentStatus::Processing,
+            "requires_action" => PaymentIntentStatus::RequiresAction,
+            "requires_capture" => PaymentIntentStatus::RequiresCapture,
+            "requires_confirmation" => PaymentIntentStatus::RequiresConfirmation,
+            "requires_payment_method" => PaymentIntentStatus::RequiresPaymentMethod,
+            "succeeded" => PaymentIntentStatus::Succeeded,
+            "requires_source" => PaymentIntentStatus::RequiresSource,
+            "requires_source_action" => PaymentIntentStatus::RequiresSourceAction,
+            _ => PaymentIntentStatus::Unknown(status.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for PaymentIntentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for PaymentIntentStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentIntentStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let status = String::deserialize(deserializer)?;
+        Ok(PaymentIntentStatus::from(status.as_str()))
+    }
+}
+
+/// The kind of action a customer must take to complete a payment or setup intent.
+#[derive(Clone, Debug, PartialEq)]
+pub enum NextActionType {
+    RedirectToUrl,
+    UseStripeSdk,
+    /// Only returned by API versions before 2019-02-11, instead of `RedirectToUrl`.
+    AuthorizeWithUrl,
+    /// An action type which isn't known to this version of the crate.
+    Unknown(String),
+}
+
+impl NextActionType {
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NextActionType::RedirectToUrl => "redirect_to_url",
+            NextActionType::UseStripeSdk => "use_stripe_sdk",
+            NextActionType::AuthorizeWithUrl => "authorize_with_url",
+            NextActionType::Unknown(ref name) => name,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for NextActionType {
+    fn from(name: &'a str) -> NextActionType {
+        match name {
+            "redirect_to_url" => NextActionType::RedirectToUrl,
+            "use_stripe_sdk" => NextActionType::UseStripeSdk,
+            "authorize_with_url" => NextActionType::AuthorizeWithUrl,
+            _ => NextActionType::Unknown(name.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for NextActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for NextActionType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for NextActionType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(NextActionType::from(name.as_str()))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RedirectToUrl {
+    pub return_url: Option<String>,
+    pub url: String,
+}
+
+/// The action a customer must take before a payment or setup intent can proceed.
+///
+/// API versions before 2019-02-11 name it `next_source_action` and use `authorize_with_url`
+/// instead of `redirect_to_url`, which are both accepted here.
+/// For more details see https://stripe.com/docs/api/payment_intents/object#payment_intent_object-next_action.
+#[derive(Debug, Deserialize)]
+pub struct NextAction {
+    #[serde(rename = "type")]
+    pub action_type: NextActionType,
+    pub authorize_with_url: Option<RedirectToUrl>, // NOTE: Only for `authorize_with_url`
+    pub redirect_to_url: Option<RedirectToUrl>, // NOTE: Only for `redirect_to_url`
+    pub use_stripe_sdk: Option<json::Value>, // NOTE: Only for `use_stripe_sdk`, its contents aren't documented
+}
+
+/// The error encountered by the last attempt to pay a payment intent or to set up a setup intent.
+#[derive(Debug, Deserialize)]
+pub struct PaymentError {
+    #[serde(rename = "type")]
+    pub error_type: String, // (api_error, card_error, idempotency_error, invalid_request_error, ...)
+    pub code: Option<String>,
+    pub decline_code: Option<String>,
+    pub message: Option<String>,
+    pub param: Option<String>,
+}
+
+/// The set of parameters that can be used when creating or updating a payment intent.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/create and https://stripe.com/docs/api/payment_intents/update.
+#[derive(Default, Serialize)]
+pub struct PaymentIntentParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_types: Option<Vec<&'a str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_method: Option<CaptureMethod>, // NOTE: Only for create
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<bool>, // NOTE: Only for create
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirmation_method: Option<ConfirmationMethod>, // NOTE: Only for create
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub off_session: Option<bool>, // NOTE: Only for create, with `confirm`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<&'a str>, // NOTE: Only for create
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<&'a str>, // NOTE: Only for create, with `confirm`
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt_email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<&'a str>, // (on_session, off_session)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_group: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when confirming a payment intent.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/confirm.
+#[derive(Default, Serialize)]
+pub struct PaymentIntentConfirmParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub off_session: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt_email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<&'a str>, // (on_session, off_session)
+}
+
+/// The set of parameters that can be used when capturing a payment intent.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/capture.
+#[derive(Default, Serialize)]
+pub struct PaymentIntentCaptureParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_to_capture: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when canceling a payment intent.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/cancel.
+#[derive(Default, Serialize)]
+pub struct PaymentIntentCancelParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_reason: Option<&'a str>, // (duplicate, fraudulent, requested_by_customer, abandoned)
+}
+
+/// The set of parameters that can be used when listing payment intents.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/list.
+#[derive(Default, Serialize)]
+pub struct PaymentIntentListParams<'a> {
+    #[serde(flatten)]
+    pub list: ListParams<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+}
+
+/// The resource representing a Stripe payment intent.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents.
+#[derive(Debug, Deserialize)]
+pub struct PaymentIntent {
+    pub id: String,
+    pub amount: u64,
+    pub amount_capturable: u64,
+    pub amount_received: u64,
+    pub application: Option<String>,
+    pub application_fee_amount: Option<u64>,
+    pub canceled_at: Option<Timestamp>,
+    pub cancellation_reason: Option<String>, // (duplicate, fraudulent, requested_by_customer, abandoned, ...)
+    pub capture_method: CaptureMethod,
+    pub charges: List<Charge>,
+    pub client_secret: Option<String>,
+    pub confirmation_method: ConfirmationMethod,
+    pub created: Timestamp,
+    pub currency: Currency,
+    pub customer: Option<Expandable<Customer>>,
+    pub description: Option<String>,
+    pub invoice: Option<Expandable<Invoice>>,
+    pub last_payment_error: Option<PaymentError>,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    #[serde(alias = "next_source_action")]
+    pub next_action: Option<NextAction>,
+    pub on_behalf_of: Option<String>,
+    pub payment_method: Option<Expandable<PaymentMethod>>,
+    #[serde(default, alias = "allowed_source_types")]
+    pub payment_method_types: Vec<String>,
+    pub receipt_email: Option<String>,
+    pub setup_future_usage: Option<String>, // (on_session, off_session)
+    pub statement_descriptor: Option<String>,
+    pub status: PaymentIntentStatus,
+    pub transfer_group: Option<String>,
+}
+
+impl PaymentIntent {
+    /// Creates a new payment intent.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/create.
+    pub fn create(client: &Client, params: PaymentIntentParams) -> Result<PaymentIntent, Error> {
+        client.post("/payment_intents", params)
+    }
+
+    /// Retrieves the details of a payment intent.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/retrieve.
+    pub fn retrieve(client: &Client, payment_intent_id: &str) -> Result<PaymentIntent, Error> {
+        client.get(&format!("/payment_intents/{}", payment_intent_id))
+    }
+
+    /// Updates a payment intent's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/update.
+    pub fn update(
+        client: &Client,
+        payment_intent_id: &str,
+        params: PaymentIntentParams,
+    ) -> Result<PaymentIntent, Error> {
+        client.post(&format!("/payment_intents/{}", payment_intent_id), params)
+    }
+
+    /// Confirms that the customer intends to pay, which attempts the payment.
+    ///
+    /// The payment intent may then require an action from the customer, see `next_action`.
+    /// For more details see https://stripe.com/docs/api/payment_intents/confirm.
+    pub fn confirm(
+        client: &Client,
+        payment_intent_id: &str,
+        params: PaymentIntentConfirmParams,
+    ) -> Result<PaymentIntent, Error> {
+        client.post(&format!("/payment_intents/{}/confirm", payment_intent_id), params)
+    }
+
+    /// Captures the funds of a payment intent which was created with the manual capture method.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/capture.
+    pub fn capture(
+        client: &Client,
+        payment_intent_id: &str,
+        params: PaymentIntentCaptureParams,
+    ) -> Result<PaymentIntent, Error> {
+        client.post(&format!("/payment_intents/{}/capture", payment_intent_id), params)
+    }
+
+    /// Cancels a payment intent, releasing any uncaptured funds.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/cancel.
+    pub fn cancel(
+        client: &Client,
+        payment_intent_id: &str,
+        params: PaymentIntentCancelParams,
+    ) -> Result<PaymentIntent, Error> {
+        client.post(&format!("/payment_intents/{}/cancel", payment_intent_id), params)
+    }
+
+    /// Lists all payment intents, optionally filtered by customer.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/list.
+    pub fn list(client: &Client, params: PaymentIntentListParams) -> Result<List<PaymentIntent>, Error> {
+        client.get_list("/payment_intents", &params)
+    }
+}
+
+impl Identifiable for PaymentIntent {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
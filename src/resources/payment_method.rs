@@ -0,0 +1,269 @@
+use client::Client;
+use error::Error;
+use params::{Expandable, Identifiable, List, ListParams, Metadata, Timestamp};
+use resources::Customer;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// The type of a payment method, which determines which of its details are set.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum PaymentMethodType {
+    AuBecsDebit,
+    #[default]
+    Card,
+    CardPresent,
+    Fpx,
+    Ideal,
+    SepaDebit,
+    /// A payment method type which isn't known to this version of the crate (eg. "sofort").
+    Unknown(String),
+}
+
+impl PaymentMethodType {
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PaymentMethodType::AuBecsDebit => "au_becs_debit",
+            PaymentMethodType::Card => "card",
+            PaymentMethodType::CardPresent => "card_present",
+            PaymentMethodType::Fpx => "fpx",
+            PaymentMethodType::Ideal => "ideal",
+            PaymentMethodType::SepaDebit => "sepa_debit",
+            PaymentMethodType::Unknown(ref name) => name,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PaymentMethodType {
+    fn from(name: &'a str) -> PaymentMethodType {
+        match name {
+            "au_becs_debit" => PaymentMethodType::AuBecsDebit,
+            "card" => PaymentMethodType::Card,
+            "card_present" => PaymentMethodType::CardPresent,
+            "fpx" => PaymentMethodType::Fpx,
+            "ideal" => PaymentMethodType::Ideal,
+            "sepa_debit" => PaymentMethodType::SepaDebit,
+            _ => PaymentMethodType::Unknown(name.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for PaymentMethodType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for PaymentMethodType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentMethodType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(PaymentMethodType::from(name.as_str()))
+    }
+}
+
+/// An address where every line is optional, as used by the billing details of a payment method.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct BillingAddress {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct BillingDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<BillingAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaymentMethodCard {
+    pub brand: String, // (amex, diners, discover, jcb, mastercard, unionpay, visa, unknown)
+    pub country: Option<String>, // eg. "US"
+    pub exp_month: u32,
+    pub exp_year: u32,
+    pub fingerprint: Option<String>,
+    pub funding: String, // (credit, debit, prepaid, unknown)
+    pub last4: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaymentMethodSepaDebit {
+    pub bank_code: Option<String>,
+    pub branch_code: Option<String>,
+    pub country: Option<String>,
+    pub fingerprint: Option<String>,
+    pub last4: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaymentMethodIdeal {
+    pub bank: Option<String>, // eg. "abn_amro"
+    pub bic: Option<String>,
+}
+
+/// The card of a new payment method, either a token or the raw card details.
+///
+/// Only `exp_month` and `exp_year` can be set when updating a payment method.
+#[derive(Default, Serialize)]
+pub struct PaymentMethodCardParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cvc: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_month: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_year: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+pub struct PaymentMethodSepaDebitParams<'a> {
+    pub iban: &'a str,
+}
+
+#[derive(Serialize)]
+pub struct PaymentMethodIdealParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when creating or updating a payment method.
+///
+/// For more details see https://stripe.com/docs/api/payment_methods/create and https://stripe.com/docs/api/payment_methods/update.
+#[derive(Default, Serialize)]
+pub struct PaymentMethodParams<'a> {
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_type: Option<PaymentMethodType>, // NOTE: Only for create
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ideal: Option<PaymentMethodIdealParams<'a>>, // NOTE: Only for create
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sepa_debit: Option<PaymentMethodSepaDebitParams<'a>>, // NOTE: Only for create
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_details: Option<BillingDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<PaymentMethodCardParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The set of parameters that can be used when attaching a payment method to a customer.
+///
+/// For more details see https://stripe.com/docs/api/payment_methods/attach.
+#[derive(Serialize)]
+pub struct PaymentMethodAttachParams<'a> {
+    pub customer: &'a str,
+}
+
+/// The set of parameters that can be used when listing the payment methods of a customer.
+///
+/// For more details see https://stripe.com/docs/api/payment_methods/list.
+#[derive(Default, Serialize)]
+pub struct PaymentMethodListParams<'a> {
+    #[serde(flatten)]
+    pub list: ListParams<'a>,
+    pub customer: &'a str,
+    #[serde(rename = "type")]
+    pub payment_method_type: PaymentMethodType,
+}
+
+/// The resource representing a Stripe payment method.
+///
+/// For more details see https://stripe.com/docs/api/payment_methods.
+#[derive(Debug, Deserialize)]
+pub struct PaymentMethod {
+    pub id: String,
+    pub billing_details: BillingDetails,
+    pub card: Option<PaymentMethodCard>, // NOTE: Only for `card` payment methods
+    pub created: Timestamp,
+    pub customer: Option<Expandable<Customer>>,
+    pub ideal: Option<PaymentMethodIdeal>, // NOTE: Only for `ideal` payment methods
+    pub livemode: bool,
+    pub metadata: Metadata,
+    pub sepa_debit: Option<PaymentMethodSepaDebit>, // NOTE: Only for `sepa_debit` payment methods
+    #[serde(rename = "type")]
+    pub payment_method_type: PaymentMethodType,
+}
+
+impl PaymentMethod {
+    /// Creates a new payment method.
+    ///
+    /// Payment methods are usually created client-side with Stripe.js instead.
+    /// For more details see https://stripe.com/docs/api/payment_methods/create.
+    pub fn create(client: &Client, params: PaymentMethodParams) -> Result<PaymentMethod, Error> {
+        client.post("/payment_methods", params)
+    }
+
+    /// Retrieves the details of a payment method.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_methods/retrieve.
+    pub fn retrieve(client: &Client, payment_method_id: &str) -> Result<PaymentMethod, Error> {
+        client.get(&format!("/payment_methods/{}", payment_method_id))
+    }
+
+    /// Updates a payment method which is attached to a customer.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_methods/update.
+    pub fn update(
+        client: &Client,
+        payment_method_id: &str,
+        params: PaymentMethodParams,
+    ) -> Result<PaymentMethod, Error> {
+        client.post(&format!("/payment_methods/{}", payment_method_id), params)
+    }
+
+    /// Lists the payment methods of a customer which have the given type.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_methods/list.
+    pub fn list(client: &Client, params: PaymentMethodListParams) -> Result<List<PaymentMethod>, Error> {
+        client.get_list("/payment_methods", &params)
+    }
+
+    /// Attaches a payment method to a customer.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_methods/attach.
+    pub fn attach(
+        client: &Client,
+        payment_method_id: &str,
+        params: PaymentMethodAttachParams,
+    ) -> Result<PaymentMethod, Error> {
+        client.post(&format!("/payment_methods/{}/attach", payment_method_id), params)
+    }
+
+    /// Detaches a payment method from its customer, after which it can no longer be used.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_methods/detach.
+    pub fn detach(client: &Client, payment_method_id: &str) -> Result<PaymentMethod, Error> {
+        client.post_empty(&format!("/payment_methods/{}/detach", payment_method_id))
+    }
+}
+
+impl Identifiable for PaymentMethod {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
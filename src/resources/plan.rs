@@ -1,7 +1,97 @@
 use error::Error;
 use client::Client;
-use params::{Metadata, Timestamp};
-use resources::{Currency, Deleted};
+use params::{Expandable, Identifiable, List, ListParams, Metadata, Timestamp};
+use resources::{Currency, Deleted, Product, ProductParams};
+use serde::{Serialize, Serializer};
+
+/// Whether the amount of a plan or price is charged per unit or computed from its tiers.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+pub enum BillingScheme {
+    #[serde(rename = "per_unit")]
+    PerUnit,
+    #[serde(rename = "tiered")]
+    Tiered,
+}
+
+/// Whether the tiers of a plan or price apply to each unit progressively or to all units at once.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+pub enum TiersMode {
+    #[serde(rename = "graduated")]
+    Graduated,
+    #[serde(rename = "volume")]
+    Volume,
+}
+
+/// Whether a plan or price bills for a quantity set on the subscription or for reported usage.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+pub enum UsageType {
+    #[serde(rename = "licensed")]
+    Licensed,
+    #[serde(rename = "metered")]
+    Metered,
+}
+
+/// How the usage reported for a metered plan or price is aggregated over a billing period.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+pub enum AggregateUsage {
+    #[serde(rename = "last_during_period")]
+    LastDuringPeriod,
+    #[serde(rename = "last_ever")]
+    LastEver,
+    #[serde(rename = "max")]
+    Max,
+    #[serde(rename = "sum")]
+    Sum,
+}
+
+/// Divides the reported usage or quantity by a number before it is billed.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TransformUsage {
+    pub divide_by: u64,
+    pub round: String, // (up, down)
+}
+
+/// The upper bound of a tier, where the last tier of a plan or price must be unbounded.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UpTo {
+    Max(u64),
+    Inf,
+}
+
+impl Serialize for UpTo {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            UpTo::Max(max) => serializer.serialize_u64(max),
+            UpTo::Inf => serializer.serialize_str("inf"),
+        }
+    }
+}
+
+/// The parameters of a single tier of a tiered plan or price.
+#[derive(Debug, Serialize)]
+pub struct TierParams {
+    pub up_to: UpTo,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flat_amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<u64>,
+}
+
+/// A single tier of a tiered plan or price.
+#[derive(Debug, Deserialize)]
+pub struct Tier {
+    pub flat_amount: Option<u64>,
+    pub unit_amount: Option<u64>,
+    pub up_to: Option<u64>, // NOTE: Missing in the last tier
+}
+
+/// The product of a new plan, either the id of an existing product or the params of a new one.
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum PlanProduct<'a> {
+    Id(&'a str),
+    Product(Box<ProductParams<'a>>),
+}
 
 /// The set of parameters that can be used when creating or updating a plan.
 ///
@@ -17,34 +107,77 @@ pub struct PlanParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub interval: Option<&'a str>, // (day, week, month, year)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<&'a str>,
+    pub name: Option<&'a str>, // NOTE: Only supported by API versions before 2018-02-05, use `product` instead
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<PlanProduct<'a>>, // NOTE: Only for create
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregate_usage: Option<AggregateUsage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_scheme: Option<BillingScheme>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tiers: Option<Vec<TierParams>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tiers_mode: Option<TiersMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transform_usage: Option<TransformUsage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage_type: Option<UsageType>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub interval_count: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub statement_descriptor: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trial_period_days: Option<u64>,
 }
 
+/// The set of parameters that can be used when listing plans.
+///
+/// For more details see https://stripe.com/docs/api#list_plans.
+#[derive(Default, Serialize)]
+pub struct PlanListParams<'a> {
+    #[serde(flatten)]
+    pub list: ListParams<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<&'a str>,
+}
+
 /// The resource representing a Stripe plan.
 ///
 /// For more details see https://stripe.com/docs/api#plans.
 #[derive(Debug, Deserialize)]
 pub struct Plan {
     pub id: String,
-    pub amount: u64,
+    pub active: Option<bool>, // NOTE: Missing before API version 2018-02-05
+    pub aggregate_usage: Option<AggregateUsage>,
+    pub amount: Option<u64>, // NOTE: Missing for tiered plans
+    pub billing_scheme: Option<BillingScheme>, // NOTE: Missing before API version 2018-02-05
     pub created: Timestamp,
     pub currency: Currency,
     pub interval: String, // (day, week, month, year)
     pub interval_count: u64,
     pub livemode: bool,
     pub metadata: Metadata,
+    #[serde(default)]
+    // NOTE: Missing since API version 2018-02-05, see `product`
     pub name: String,
+    pub nickname: Option<String>,
+    pub product: Option<Expandable<Product>>, // NOTE: Missing before API version 2018-02-05
     pub statement_descriptor: Option<String>,
+    pub tiers: Option<Vec<Tier>>,
+    pub tiers_mode: Option<TiersMode>,
+    pub transform_usage: Option<TransformUsage>,
     pub trial_period_days: Option<u64>,
+    pub usage_type: Option<UsageType>, // NOTE: Missing before API version 2018-02-05
 }
 
 impl Plan {
@@ -75,4 +208,17 @@ impl Plan {
     pub fn delete(client: &Client, plan_id: &str) -> Result<Deleted, Error> {
         client.delete(&format!("/plans/{}", plan_id))
     }
+
+    /// Lists all plans, optionally filtered by product or whether they're active.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_plans.
+    pub fn list(client: &Client, params: PlanListParams) -> Result<List<Plan>, Error> {
+        client.get_list("/plans", &params)
+    }
+}
+
+impl Identifiable for Plan {
+    fn id(&self) -> &str {
+        &self.id
+    }
 }
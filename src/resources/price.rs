@@ -0,0 +1,149 @@
+use client::Client;
+use error::Error;
+use params::{Expandable, Identifiable, List, ListParams, Metadata, Timestamp};
+use resources::{AggregateUsage, BillingScheme, Currency, Product, Tier, TierParams, TiersMode, TransformUsage,
+                UsageType};
+
+/// Whether a price is charged once or on every billing period of a subscription.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+pub enum PriceType {
+    #[serde(rename = "one_time")]
+    OneTime,
+    #[serde(rename = "recurring")]
+    Recurring,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RecurringParams<'a> {
+    pub interval: &'a str, // (day, week, month, year)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval_count: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregate_usage: Option<AggregateUsage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage_type: Option<UsageType>,
+}
+
+/// The billing period of a recurring price.
+#[derive(Debug, Deserialize)]
+pub struct Recurring {
+    pub aggregate_usage: Option<AggregateUsage>,
+    pub interval: String, // (day, week, month, year)
+    pub interval_count: u64,
+    pub usage_type: UsageType,
+}
+
+/// The set of parameters that can be used when creating or updating a price.
+///
+/// For more details see https://stripe.com/docs/api/prices/create and https://stripe.com/docs/api/prices/update.
+#[derive(Default, Serialize)]
+pub struct PriceParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>, // NOTE: Only for create
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<&'a str>, // NOTE: Only for create
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<u64>, // NOTE: Only for create
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_scheme: Option<BillingScheme>, // NOTE: Only for create
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring: Option<RecurringParams<'a>>, // NOTE: Only for create
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tiers: Option<Vec<TierParams>>, // NOTE: Only for create
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tiers_mode: Option<TiersMode>, // NOTE: Only for create
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transform_quantity: Option<TransformUsage>, // NOTE: Only for create
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lookup_key: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_lookup_key: Option<bool>,
+}
+
+/// The set of parameters that can be used when listing prices.
+///
+/// For more details see https://stripe.com/docs/api/prices/list.
+#[derive(Default, Serialize)]
+pub struct PriceListParams<'a> {
+    #[serde(flatten)]
+    pub list: ListParams<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lookup_keys: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<&'a str>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_type: Option<PriceType>,
+}
+
+/// The resource representing a Stripe price.
+///
+/// For more details see https://stripe.com/docs/api/prices.
+#[derive(Debug, Deserialize)]
+pub struct Price {
+    pub id: String,
+    pub active: bool,
+    pub billing_scheme: BillingScheme,
+    pub created: Timestamp,
+    pub currency: Currency,
+    pub livemode: bool,
+    pub lookup_key: Option<String>,
+    pub metadata: Metadata,
+    pub nickname: Option<String>,
+    pub product: Expandable<Product>,
+    pub recurring: Option<Recurring>,
+    pub tiers: Option<Vec<Tier>>, // NOTE: Only present when expanded
+    pub tiers_mode: Option<TiersMode>,
+    pub transform_quantity: Option<TransformUsage>,
+    #[serde(rename = "type")]
+    pub price_type: PriceType,
+    pub unit_amount: Option<u64>, // NOTE: Missing for tiered prices
+}
+
+impl Price {
+    /// Creates a new price for an existing product.
+    ///
+    /// For more details see https://stripe.com/docs/api/prices/create.
+    pub fn create(client: &Client, params: PriceParams) -> Result<Price, Error> {
+        client.post("/prices", params)
+    }
+
+    /// Retrieves the details of a price.
+    ///
+    /// For more details see https://stripe.com/docs/api/prices/retrieve.
+    pub fn retrieve(client: &Client, price_id: &str) -> Result<Price, Error> {
+        client.get(&format!("/prices/{}", price_id))
+    }
+
+    /// Updates a price's properties.
+    ///
+    /// Prices can't be deleted, instead they are archived by setting `active` to false.
+    /// For more details see https://stripe.com/docs/api/prices/update.
+    pub fn update(client: &Client, price_id: &str, params: PriceParams) -> Result<Price, Error> {
+        client.post(&format!("/prices/{}", price_id), params)
+    }
+
+    /// Lists all prices, optionally filtered by product, type or whether they're active.
+    ///
+    /// For more details see https://stripe.com/docs/api/prices/list.
+    pub fn list(client: &Client, params: PriceListParams) -> Result<List<Price>, Error> {
+        client.get_list("/prices", &params)
+    }
+}
+
+impl Identifiable for Price {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
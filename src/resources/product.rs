@@ -0,0 +1,148 @@
+use client::Client;
+use error::Error;
+use params::{Identifiable, List, ListParams, Metadata, Timestamp};
+use resources::Deleted;
+
+/// Whether a product is a service which is sold with plans, or a good which is sold with SKUs.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+pub enum ProductType {
+    #[serde(rename = "good")]
+    Good,
+    #[serde(rename = "service")]
+    Service,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PackageDimensions {
+    pub height: f64,
+    pub length: f64,
+    pub weight: f64,
+    pub width: f64,
+}
+
+/// The set of parameters that can be used when creating or updating a product.
+///
+/// For more details see https://stripe.com/docs/api#create_product and https://stripe.com/docs/api#update_product.
+#[derive(Default, Serialize)]
+pub struct ProductParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<&'a str>, // NOTE: Only for create
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_type: Option<ProductType>, // NOTE: Only for create
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<Vec<&'a str>>, // NOTE: Only for goods
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<&'a str>, // NOTE: Only for goods
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>, // NOTE: Only for goods
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub images: Option<Vec<&'a str>>, // NOTE: Only for goods
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package_dimensions: Option<PackageDimensions>, // NOTE: Only for goods
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shippable: Option<bool>, // NOTE: Only for goods
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>, // NOTE: Only for services
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_label: Option<&'a str>, // NOTE: Only for services
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<&'a str>, // NOTE: Only for goods
+}
+
+/// The set of parameters that can be used when listing products.
+///
+/// For more details see https://stripe.com/docs/api#list_products.
+#[derive(Default, Serialize)]
+pub struct ProductListParams<'a> {
+    #[serde(flatten)]
+    pub list: ListParams<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ids: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shippable: Option<bool>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_type: Option<ProductType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<&'a str>,
+}
+
+/// The resource representing a Stripe product.
+///
+/// For more details see https://stripe.com/docs/api#products.
+#[derive(Debug, Deserialize)]
+pub struct Product {
+    pub id: String,
+    pub active: bool,
+    #[serde(default)]
+    pub attributes: Vec<String>,
+    pub caption: Option<String>,
+    pub created: Timestamp,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub images: Vec<String>,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    pub name: String,
+    pub package_dimensions: Option<PackageDimensions>,
+    pub shippable: Option<bool>,
+    pub statement_descriptor: Option<String>,
+    #[serde(rename = "type")]
+    pub product_type: ProductType,
+    pub unit_label: Option<String>,
+    pub updated: Timestamp,
+    pub url: Option<String>,
+}
+
+impl Product {
+    /// Creates a new product.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_product.
+    pub fn create(client: &Client, params: ProductParams) -> Result<Product, Error> {
+        client.post("/products", params)
+    }
+
+    /// Retrieves the details of a product.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_product.
+    pub fn retrieve(client: &Client, product_id: &str) -> Result<Product, Error> {
+        client.get(&format!("/products/{}", product_id))
+    }
+
+    /// Updates a product's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_product.
+    pub fn update(client: &Client, product_id: &str, params: ProductParams) -> Result<Product, Error> {
+        client.post(&format!("/products/{}", product_id), params)
+    }
+
+    /// Deletes a product which has no plans or SKUs.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_product.
+    pub fn delete(client: &Client, product_id: &str) -> Result<Deleted, Error> {
+        client.delete(&format!("/products/{}", product_id))
+    }
+
+    /// Lists all products, optionally filtered by type or whether they're active.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_products.
+    pub fn list(client: &Client, params: ProductListParams) -> Result<List<Product>, Error> {
+        client.get_list("/products", &params)
+    }
+}
+
+impl Identifiable for Product {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
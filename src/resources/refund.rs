@@ -1,12 +1,47 @@
-use params::{Metadata, Timestamp};
-use resources::Currency;
+use client::Client;
+use error::Error;
+use params::{Expandable, Identifiable, List, ListParams, Metadata, Timestamp};
+use resources::{Charge, Currency};
 
+/// The set of parameters that can be used when creating or updating a refund.
+///
+/// For more details see https://stripe.com/docs/api#create_refund and https://stripe.com/docs/api#update_refund.
+#[derive(Default, Serialize)]
+pub struct RefundParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charge: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<&'a str>, // (duplicate, fraudulent, requested_by_customer)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_application_fee: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reverse_transfer: Option<bool>,
+}
+
+/// The set of parameters that can be used when listing refunds.
+///
+/// For more details see https://stripe.com/docs/api#list_refunds.
+#[derive(Default, Serialize)]
+pub struct RefundListParams<'a> {
+    #[serde(flatten)]
+    pub list: ListParams<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charge: Option<&'a str>,
+}
+
+/// The resource representing a Stripe refund.
+///
+/// For more details see https://stripe.com/docs/api#refunds.
 #[derive(Debug, Deserialize)]
 pub struct Refund {
     pub id: String,
     pub amount: u64,
     pub balance_transaction: String,
-    pub charge: String,
+    pub charge: Expandable<Charge>,
     pub created: Timestamp,
     pub currency: Currency,
     pub description: String,
@@ -15,3 +50,39 @@ pub struct Refund {
     pub receipt_number: Option<String>,
     pub status: String, // (succeeded, pending, failed, cancelled)
 }
+
+impl Refund {
+    /// Refunds a charge, in full unless an amount is given.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_refund.
+    pub fn create(client: &Client, params: RefundParams) -> Result<Refund, Error> {
+        client.post("/refunds", params)
+    }
+
+    /// Retrieves the details of a refund.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_refund.
+    pub fn retrieve(client: &Client, refund_id: &str) -> Result<Refund, Error> {
+        client.get(&format!("/refunds/{}", refund_id))
+    }
+
+    /// Updates a refund's metadata.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_refund.
+    pub fn update(client: &Client, refund_id: &str, params: RefundParams) -> Result<Refund, Error> {
+        client.post(&format!("/refunds/{}", refund_id), params)
+    }
+
+    /// Lists all refunds, optionally filtered by charge.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_refunds.
+    pub fn list(client: &Client, params: RefundListParams) -> Result<List<Refund>, Error> {
+        client.get_list("/refunds", &params)
+    }
+}
+
+impl Identifiable for Refund {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
@@ -0,0 +1,221 @@
+use client::Client;
+use error::Error;
+use params::{Expandable, Identifiable, List, ListParams, Metadata, Timestamp};
+use resources::{Customer, NextAction, PaymentError, PaymentMethod};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Whether the payment method saved by a setup intent will be used while the customer is present.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+pub enum SetupIntentUsage {
+    #[serde(rename = "off_session")]
+    OffSession,
+    #[serde(rename = "on_session")]
+    OnSession,
+}
+
+/// The status of a setup intent.
+///
+/// For more details see https://stripe.com/docs/payments/intents#intent-statuses.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SetupIntentStatus {
+    Canceled,
+    Processing,
+    RequiresAction,
+    RequiresConfirmation,
+    RequiresPaymentMethod,
+    Succeeded,
+    /// A status which isn't known to this version of the crate.
+    Unknown(String),
+}
+
+impl SetupIntentStatus {
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SetupIntentStatus::Canceled => "canceled",
+            SetupIntentStatus::Processing => "processing",
+            SetupIntentStatus::RequiresAction => "requires_action",
+            SetupIntentStatus::RequiresConfirmation => "requires_confirmation",
+            SetupIntentStatus::RequiresPaymentMethod => "requires_payment_method",
+            SetupIntentStatus::Succeeded => "succeeded",
+            SetupIntentStatus::Unknown(ref status) => status,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SetupIntentStatus {
+    fn from(status: &'a str) -> SetupIntentStatus {
+        match status {
+            "canceled" => SetupIntentStatus::Canceled,
+            "processing" => SetupIntentStatus::Processing,
+            "requires_action" => SetupIntentStatus::RequiresAction,
+            "requires_confirmation" => SetupIntentStatus::RequiresConfirmation,
+            "requires_payment_method" => SetupIntentStatus::RequiresPaymentMethod,
+            "succeeded" => SetupIntentStatus::Succeeded,
+            _ => SetupIntentStatus::Unknown(status.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for SetupIntentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for SetupIntentStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for SetupIntentStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let status = String::deserialize(deserializer)?;
+        Ok(SetupIntentStatus::from(status.as_str()))
+    }
+}
+
+/// The set of parameters that can be used when creating or updating a setup intent.
+///
+/// For more details see https://stripe.com/docs/api/setup_intents/create and https://stripe.com/docs/api/setup_intents/update.
+#[derive(Default, Serialize)]
+pub struct SetupIntentParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<bool>, // NOTE: Only for create
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<&'a str>, // NOTE: Only for create
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<&'a str>, // NOTE: Only for create, with `confirm`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<SetupIntentUsage>, // NOTE: Only for create
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_types: Option<Vec<&'a str>>,
+}
+
+/// The set of parameters that can be used when confirming a setup intent.
+///
+/// For more details see https://stripe.com/docs/api/setup_intents/confirm.
+#[derive(Default, Serialize)]
+pub struct SetupIntentConfirmParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when canceling a setup intent.
+///
+/// For more details see https://stripe.com/docs/api/setup_intents/cancel.
+#[derive(Default, Serialize)]
+pub struct SetupIntentCancelParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_reason: Option<&'a str>, // (abandoned, duplicate, requested_by_customer)
+}
+
+/// The set of parameters that can be used when listing setup intents.
+///
+/// For more details see https://stripe.com/docs/api/setup_intents/list.
+#[derive(Default, Serialize)]
+pub struct SetupIntentListParams<'a> {
+    #[serde(flatten)]
+    pub list: ListParams<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<&'a str>,
+}
+
+/// The resource representing a Stripe setup intent.
+///
+/// For more details see https://stripe.com/docs/api/setup_intents.
+#[derive(Debug, Deserialize)]
+pub struct SetupIntent {
+    pub id: String,
+    pub application: Option<String>,
+    pub cancellation_reason: Option<String>, // (abandoned, duplicate, requested_by_customer)
+    pub client_secret: Option<String>,
+    pub created: Timestamp,
+    pub customer: Option<Expandable<Customer>>,
+    pub description: Option<String>,
+    pub last_setup_error: Option<PaymentError>,
+    pub livemode: bool,
+    pub mandate: Option<String>,
+    pub metadata: Metadata,
+    pub next_action: Option<NextAction>,
+    pub on_behalf_of: Option<String>,
+    pub payment_method: Option<Expandable<PaymentMethod>>,
+    #[serde(default)]
+    pub payment_method_types: Vec<String>,
+    pub single_use_mandate: Option<String>,
+    pub status: SetupIntentStatus,
+    pub usage: SetupIntentUsage,
+}
+
+impl SetupIntent {
+    /// Creates a new setup intent.
+    ///
+    /// For more details see https://stripe.com/docs/api/setup_intents/create.
+    pub fn create(client: &Client, params: SetupIntentParams) -> Result<SetupIntent, Error> {
+        client.post("/setup_intents", params)
+    }
+
+    /// Retrieves the details of a setup intent.
+    ///
+    /// For more details see https://stripe.com/docs/api/setup_intents/retrieve.
+    pub fn retrieve(client: &Client, setup_intent_id: &str) -> Result<SetupIntent, Error> {
+        client.get(&format!("/setup_intents/{}", setup_intent_id))
+    }
+
+    /// Updates a setup intent's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api/setup_intents/update.
+    pub fn update(client: &Client, setup_intent_id: &str, params: SetupIntentParams) -> Result<SetupIntent, Error> {
+        client.post(&format!("/setup_intents/{}", setup_intent_id), params)
+    }
+
+    /// Confirms that the customer intends to save their payment method.
+    ///
+    /// The setup intent may then require an action from the customer, see `next_action`.
+    /// For more details see https://stripe.com/docs/api/setup_intents/confirm.
+    pub fn confirm(
+        client: &Client,
+        setup_intent_id: &str,
+        params: SetupIntentConfirmParams,
+    ) -> Result<SetupIntent, Error> {
+        client.post(&format!("/setup_intents/{}/confirm", setup_intent_id), params)
+    }
+
+    /// Cancels a setup intent which hasn't succeeded yet.
+    ///
+    /// For more details see https://stripe.com/docs/api/setup_intents/cancel.
+    pub fn cancel(
+        client: &Client,
+        setup_intent_id: &str,
+        params: SetupIntentCancelParams,
+    ) -> Result<SetupIntent, Error> {
+        client.post(&format!("/setup_intents/{}/cancel", setup_intent_id), params)
+    }
+
+    /// Lists all setup intents, optionally filtered by customer or payment method.
+    ///
+    /// For more details see https://stripe.com/docs/api/setup_intents/list.
+    pub fn list(client: &Client, params: SetupIntentListParams) -> Result<List<SetupIntent>, Error> {
+        client.get_list("/setup_intents", &params)
+    }
+}
+
+impl Identifiable for SetupIntent {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
@@ -1,7 +1,10 @@
 use error::Error;
 use client::Client;
-use resources::{Address, Card, Currency};
-use params::Metadata;
+use resources::{Address, BankAccount, Card, Currency};
+use params::{Identifiable, Metadata};
+use serde::{Deserialize, Deserializer};
+use serde::de::Error as DeError;
+use serde_json as json;
 
 #[derive(Serialize)]
 pub struct OwnerParams<'a> {
@@ -44,12 +47,29 @@ pub struct SourceParams<'a> {
     pub usage: Option<&'a str>, // (reusable, single-use)
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(tag = "object")]
+/// A payment source of a customer or charge.
+///
+/// Sources which aren't modeled by this crate (eg. the "src_..." objects of the sources API)
+/// are deserialized into `Source::Unknown`.
+#[derive(Debug)]
 pub enum Source {
     // BitcoinReceiver(...),
-    #[serde(rename = "card")]
+    BankAccount(BankAccount),
     Card(Card),
+    Unknown(json::Value),
+}
+
+impl<'de> Deserialize<'de> for Source {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = json::Value::deserialize(deserializer)?;
+        let object = value.get("object").and_then(|object| object.as_str()).map(|object| object.to_string());
+        let result = match object.as_deref() {
+            Some("bank_account") => json::from_value(value).map(Source::BankAccount),
+            Some("card") => json::from_value(value).map(Source::Card),
+            _ => Ok(Source::Unknown(value)),
+        };
+        result.map_err(D::Error::custom)
+    }
 }
 
 impl Source {
@@ -65,3 +85,13 @@ impl Source {
         client.post(&format!("/source/{}", source_id), params)
     }
 }
+
+impl Identifiable for Source {
+    fn id(&self) -> &str {
+        match *self {
+            Source::BankAccount(ref bank_account) => &bank_account.id,
+            Source::Card(ref card) => &card.id,
+            Source::Unknown(ref value) => value.get("id").and_then(|id| id.as_str()).unwrap_or(""),
+        }
+    }
+}
@@ -1,11 +1,11 @@
 use error::Error;
 use client::Client;
-use resources::{Discount, Plan};
-use params::{List, Metadata, Timestamp};
-use serde_qs as qs;
+use resources::{Customer, Deleted, Discount, Plan};
+use params::{Expandable, Identifiable, List, ListParams, Metadata, Timestamp};
 
 #[derive(Default, Serialize)]
 pub struct CancelParams {
+    // NOTE: Only supported by API versions before 2018-08-23, update `cancel_at_period_end` instead
     #[serde(skip_serializing_if = "Option::is_none")]
     pub at_period_end: Option<bool>,
 }
@@ -27,6 +27,8 @@ pub struct SubscriptionParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_fee_percent: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_at_period_end: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub coupon: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub items: Option<Vec<ItemParams<'a>>>,
@@ -50,6 +52,58 @@ pub struct SubscriptionParams<'a> {
     pub trial_period_days: Option<u64>,
 }
 
+/// The set of parameters that can be used when creating or updating a subscription item.
+///
+/// For more details see https://stripe.com/docs/api#create_subscription_item and https://stripe.com/docs/api#update_subscription_item.
+#[derive(Default, Serialize, Debug)]
+pub struct SubscriptionItemParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription: Option<&'a str>, // NOTE: Only for create
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prorate: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_date: Option<Timestamp>,
+}
+
+impl<'a> From<ItemParams<'a>> for SubscriptionItemParams<'a> {
+    fn from(item: ItemParams<'a>) -> Self {
+        SubscriptionItemParams {
+            plan: Some(item.plan),
+            quantity: item.quantity,
+            ..Default::default()
+        }
+    }
+}
+
+/// The set of parameters that can be used when deleting a subscription item.
+///
+/// For more details see https://stripe.com/docs/api#delete_subscription_item.
+#[derive(Default, Serialize, Debug)]
+pub struct SubscriptionItemDeleteParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clear_usage: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prorate: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_date: Option<Timestamp>,
+}
+
+/// The set of parameters that can be used when listing the items of a subscription.
+///
+/// For more details see https://stripe.com/docs/api#list_subscription_items.
+#[derive(Default, Serialize)]
+pub struct SubscriptionItemListParams<'a> {
+    #[serde(flatten)]
+    pub list: ListParams<'a>,
+    pub subscription: &'a str,
+}
+
 /// The resource representing a Stripe subscription item.
 ///
 /// For more details see https://stripe.com/docs/api#subscription_items.
@@ -57,8 +111,11 @@ pub struct SubscriptionParams<'a> {
 pub struct SubscriptionItem {
     pub id: String,
     pub created: Timestamp,
+    #[serde(default)]
+    pub metadata: Metadata,
     pub plan: Plan,
     pub quantity: u64,
+    pub subscription: Option<String>, // NOTE: Missing in older API versions
 }
 
 /// The resource representing a Stripe subscription.
@@ -73,7 +130,7 @@ pub struct Subscription {
     pub created: Option<Timestamp>,
     pub current_period_start: Timestamp,
     pub current_period_end: Timestamp,
-    pub customer: String,
+    pub customer: Expandable<Customer>,
     pub discount: Option<Discount>,
     pub ended_at: Option<Timestamp>,
     pub items: List<SubscriptionItem>,
@@ -113,6 +170,62 @@ impl Subscription {
     ///
     /// For more details see https://stripe.com/docs/api#cancel_subscription.
     pub fn cancel(client: &Client, subscription_id: &str, params: CancelParams) -> Result<Subscription, Error> {
-        client.delete(&format!("/subscriptions/{}?{}", subscription_id, qs::to_string(&params)?))
+        client.delete_query(&format!("/subscriptions/{}", subscription_id), params)
+    }
+
+    /// Removes the discount applied to a subscription.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_subscription_discount.
+    pub fn delete_discount(client: &Client, subscription_id: &str) -> Result<Deleted, Error> {
+        client.delete(&format!("/subscriptions/{}/discount", subscription_id))
+    }
+}
+
+impl SubscriptionItem {
+    /// Adds a new item to an existing subscription.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_subscription_item.
+    pub fn create(client: &Client, params: SubscriptionItemParams) -> Result<SubscriptionItem, Error> {
+        client.post("/subscription_items", params)
+    }
+
+    /// Retrieves the details of a subscription item.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_subscription_item.
+    pub fn retrieve(client: &Client, item_id: &str) -> Result<SubscriptionItem, Error> {
+        client.get(&format!("/subscription_items/{}", item_id))
+    }
+
+    /// Updates the plan or quantity of a subscription item.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_subscription_item.
+    pub fn update(client: &Client, item_id: &str, params: SubscriptionItemParams) -> Result<SubscriptionItem, Error> {
+        client.post(&format!("/subscription_items/{}", item_id), params)
+    }
+
+    /// Deletes an item from its subscription, optionally clearing its usage for metered plans.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_subscription_item.
+    pub fn delete(client: &Client, item_id: &str, params: SubscriptionItemDeleteParams) -> Result<Deleted, Error> {
+        client.delete_query(&format!("/subscription_items/{}", item_id), params)
+    }
+
+    /// Lists the items of a subscription.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_subscription_items.
+    pub fn list(client: &Client, params: SubscriptionItemListParams) -> Result<List<SubscriptionItem>, Error> {
+        client.get_list("/subscription_items", &params)
+    }
+}
+
+impl Identifiable for Subscription {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl Identifiable for SubscriptionItem {
+    fn id(&self) -> &str {
+        &self.id
     }
 }
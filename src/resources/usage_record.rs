@@ -0,0 +1,93 @@
+use client::Client;
+use error::Error;
+use params::{Identifiable, List, ListParams, Timestamp};
+
+/// How the quantity of a usage record is combined with the usage already reported.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+pub enum UsageRecordAction {
+    #[serde(rename = "increment")]
+    Increment,
+    #[serde(rename = "set")]
+    Set,
+}
+
+/// The set of parameters that can be used when creating a usage record.
+///
+/// For more details see https://stripe.com/docs/api#usage_record_create.
+#[derive(Serialize)]
+pub struct UsageRecordParams {
+    pub quantity: u64,
+    pub timestamp: Timestamp,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<UsageRecordAction>, // NOTE: Defaults to `increment`
+}
+
+/// The resource representing a Stripe usage record.
+///
+/// For more details see https://stripe.com/docs/api#usage_records.
+#[derive(Debug, Deserialize)]
+pub struct UsageRecord {
+    pub id: String,
+    pub livemode: bool,
+    pub quantity: u64,
+    pub subscription_item: String,
+    pub timestamp: Timestamp,
+}
+
+/// The billing period covered by a usage record summary.
+#[derive(Debug, Deserialize)]
+pub struct UsagePeriod {
+    pub start: Option<Timestamp>,
+    pub end: Option<Timestamp>,
+}
+
+/// The resource representing the total usage of a subscription item over a billing period.
+///
+/// For more details see https://stripe.com/docs/api#usage_record_summary_object.
+#[derive(Debug, Deserialize)]
+pub struct UsageRecordSummary {
+    pub id: String,
+    pub invoice: Option<String>,
+    pub livemode: bool,
+    pub period: UsagePeriod,
+    pub subscription_item: String,
+    pub total_usage: u64,
+}
+
+impl UsageRecord {
+    /// Reports the usage of a subscription item on a metered plan.
+    ///
+    /// For more details see https://stripe.com/docs/api#usage_record_create.
+    pub fn create(
+        client: &Client,
+        subscription_item_id: &str,
+        params: UsageRecordParams,
+    ) -> Result<UsageRecord, Error> {
+        client.post(&format!("/subscription_items/{}/usage_records", subscription_item_id), params)
+    }
+}
+
+impl UsageRecordSummary {
+    /// Lists the usage of a subscription item for each of its billing periods.
+    ///
+    /// For more details see https://stripe.com/docs/api#usage_record_summary_list.
+    pub fn list(
+        client: &Client,
+        subscription_item_id: &str,
+        params: ListParams,
+    ) -> Result<List<UsageRecordSummary>, Error> {
+        client.get_list(&format!("/subscription_items/{}/usage_record_summaries", subscription_item_id), &params)
+    }
+}
+
+impl Identifiable for UsageRecord {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl Identifiable for UsageRecordSummary {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
@@ -0,0 +1,155 @@
+use error::WebhookError;
+use hmac::{Hmac, Mac};
+use params::Timestamp;
+use resources::Event;
+use serde_json as json;
+use sha2::Sha256;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The default number of seconds that a webhook's timestamp may differ from the current time.
+pub const DEFAULT_TOLERANCE: u64 = 300;
+
+/// Verifies and parses the events sent to a webhook endpoint.
+///
+/// For more details see https://stripe.com/docs/webhooks#signatures.
+pub struct Webhook;
+
+impl Webhook {
+    /// Verifies the Stripe-Signature header of a webhook and parses its payload as an `Event`,
+    /// rejecting webhooks that were signed more than `DEFAULT_TOLERANCE` seconds ago.
+    ///
+    /// The payload must be the raw body of the request, exactly as it was received.
+    pub fn construct_event(payload: &str, signature: &str, secret: &str) -> Result<Event, WebhookError> {
+        Webhook::construct_event_with_tolerance(payload, signature, secret, DEFAULT_TOLERANCE)
+    }
+
+    /// Verifies the Stripe-Signature header of a webhook and parses its payload as an `Event`,
+    /// rejecting webhooks that were signed more than `tolerance` seconds ago.
+    pub fn construct_event_with_tolerance(
+        payload: &str,
+        signature: &str,
+        secret: &str,
+        tolerance: u64,
+    ) -> Result<Event, WebhookError> {
+        Webhook::verify_signature(payload, signature, secret, tolerance)?;
+        json::from_str(payload).map_err(WebhookError::BadParse)
+    }
+
+    /// Verifies that the Stripe-Signature header matches the payload, without parsing the payload.
+    pub fn verify_signature(payload: &str, signature: &str, secret: &str, tolerance: u64) -> Result<(), WebhookError> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+        verify_signature_at(payload, signature, secret, tolerance, now as Timestamp)
+    }
+}
+
+fn verify_signature_at(
+    payload: &str,
+    signature: &str,
+    secret: &str,
+    tolerance: u64,
+    now: Timestamp,
+) -> Result<(), WebhookError> {
+    let mut timestamp = None;
+    let mut signatures = Vec::new();
+    for pair in signature.split(',') {
+        let mut parts = pair.trim().splitn(2, '=');
+        match (parts.next(), parts.next()) {
+            (Some("t"), Some(value)) => {
+                timestamp = Some(value.parse::<Timestamp>().map_err(|_| WebhookError::BadHeader)?);
+            }
+            (Some("v1"), Some(value)) => {
+                // NOTE: Signatures that aren't valid hex can't match, so they're ignored.
+                if let Some(bytes) = decode_hex(value) {
+                    signatures.push(bytes);
+                }
+            }
+            _ => {} // (v0 and other schemes aren't verified)
+        }
+    }
+    let timestamp = timestamp.ok_or(WebhookError::BadHeader)?;
+    if signatures.is_empty() {
+        return Err(WebhookError::NoSignatures);
+    }
+
+    let mut mac = Hmac::<Sha256>::new_varkey(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.input(timestamp.to_string().as_bytes());
+    mac.input(b".");
+    mac.input(payload.as_bytes());
+    if !signatures.iter().any(|signature| mac.clone().verify(signature).is_ok()) {
+        return Err(WebhookError::BadSignature);
+    }
+
+    // NOTE: The difference overflows for absurd timestamps, which are never within the tolerance.
+    match now.checked_sub(timestamp).and_then(i64::checked_abs) {
+        Some(age) if age as u64 <= tolerance => Ok(()),
+        _ => Err(WebhookError::BadTimestamp(timestamp)),
+    }
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 == 1 {
+        return None;
+    }
+    let mut bytes = Vec::with_capacity(hex.len() / 2);
+    for i in 0..hex.len() / 2 {
+        bytes.push(u8::from_str_radix(hex.get(2 * i..2 * i + 2)?, 16).ok()?);
+    }
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::verify_signature_at;
+    use error::WebhookError;
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    const PAYLOAD: &str = r#"{"id": "evt_test_webhook", "object": "event"}"#;
+    const SECRET: &str = "whsec_test_secret";
+
+    fn sign(timestamp: i64, secret: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_varkey(secret.as_bytes()).unwrap();
+        mac.input(format!("{}.{}", timestamp, PAYLOAD).as_bytes());
+        mac.result().code().iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn verify_signature() {
+        let header = format!("t=12345,v1={},v0=6ffbb59b2300aae63f2720", sign(12345, SECRET));
+        assert!(verify_signature_at(PAYLOAD, &header, SECRET, 300, 12345).is_ok());
+
+        let header = format!("t=12345,v1={},v1={}", sign(12345, "whsec_other"), sign(12345, SECRET));
+        assert!(verify_signature_at(PAYLOAD, &header, SECRET, 300, 12345).is_ok());
+    }
+
+    #[test]
+    fn verify_signature_errors() {
+        match verify_signature_at(PAYLOAD, "v1=abcd", SECRET, 300, 12345) {
+            Err(WebhookError::BadHeader) => {}
+            result => panic!("expected BadHeader, got {:?}", result),
+        }
+        match verify_signature_at(PAYLOAD, "t=12345", SECRET, 300, 12345) {
+            Err(WebhookError::NoSignatures) => {}
+            result => panic!("expected NoSignatures, got {:?}", result),
+        }
+
+        let header = format!("t=12345,v1={}", sign(12345, "whsec_other"));
+        match verify_signature_at(PAYLOAD, &header, SECRET, 300, 12345) {
+            Err(WebhookError::BadSignature) => {}
+            result => panic!("expected BadSignature, got {:?}", result),
+        }
+
+        let header = format!("t=12345,v1={}", sign(12345, SECRET));
+        match verify_signature_at(PAYLOAD, &header, SECRET, 300, 12345 + 301) {
+            Err(WebhookError::BadTimestamp(12345)) => {}
+            result => panic!("expected BadTimestamp, got {:?}", result),
+        }
+
+        let min = i64::MIN;
+        let header = format!("t={},v1={}", min, sign(min, SECRET));
+        match verify_signature_at(PAYLOAD, &header, SECRET, 300, 12345) {
+            Err(WebhookError::BadTimestamp(timestamp)) if timestamp == min => {}
+            result => panic!("expected BadTimestamp, got {:?}", result),
+        }
+    }
+}
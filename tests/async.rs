@@ -0,0 +1,69 @@
+#![cfg(feature = "async")]
+
+extern crate futures;
+extern crate futures_cpupool;
+extern crate stripe;
+
+mod common;
+
+use common::mock_server;
+use futures::{Future, Stream};
+
+#[test]
+fn async_customer_delete() {
+    let (url, request) = mock_server(vec![
+        (200, r#"{"id": "cus_1", "deleted": true}"#),
+        (200, r#"{"id": "cus_2", "deleted": true}"#),
+    ]);
+    let client = stripe::AsyncClient::from_blocking(
+        stripe::Client::builder("sk_key").api_base(url).build(),
+        futures_cpupool::CpuPool::new(1),
+    );
+    let first = client.request(|client| stripe::Customer::delete(client, "cus_1"));
+    let second = client.request(|client| stripe::Customer::delete(client, "cus_2"));
+    let (first, second) = first.join(second).wait().unwrap();
+    assert_eq!((first.id.as_str(), second.id.as_str()), ("cus_1", "cus_2"));
+
+    let heads: Vec<String> = request.iter().collect();
+    assert_eq!(heads[0].lines().next(), Some("DELETE /v1/customers/cus_1 HTTP/1.1"));
+    assert_eq!(heads[1].lines().next(), Some("DELETE /v1/customers/cus_2 HTTP/1.1"));
+}
+
+#[test]
+fn async_event_list_paginate() {
+    let first = r#"{
+        "object": "list",
+        "url": "/v1/events",
+        "has_more": true,
+        "data": [{"id": "evt_1", "created": 1502740000, "livemode": false, "pending_webhooks": 0,
+                  "type": "payout.paid", "data": {"object": {"id": "po_1", "object": "payout"}}}]
+    }"#;
+    let second = r#"{
+        "object": "list",
+        "url": "/v1/events",
+        "has_more": false,
+        "data": [{"id": "evt_2", "created": 1502740000, "livemode": false, "pending_webhooks": 0,
+                  "type": "payout.paid", "data": {"object": {"id": "po_2", "object": "payout"}}}]
+    }"#;
+    let (url, request) = mock_server(vec![(200, first), (200, second)]);
+    let client = stripe::AsyncClient::from_blocking(
+        stripe::Client::builder("sk_key").api_base(url).build(),
+        futures_cpupool::CpuPool::new(1),
+    );
+    let events = client
+        .request(|client| {
+            let params = stripe::EventListParams {
+                list: stripe::ListParams { limit: Some(1), ..Default::default() },
+                ..Default::default()
+            };
+            stripe::Event::list(client, params)
+        })
+        .wait()
+        .unwrap();
+    let ids: Vec<String> = events.paginate_async(&client).map(|event| event.id).collect().wait().unwrap();
+    assert_eq!(ids, vec!["evt_1", "evt_2"]);
+
+    let heads: Vec<String> = request.iter().collect();
+    assert_eq!(heads[0].lines().next(), Some("GET /v1/events?limit=1 HTTP/1.1"));
+    assert_eq!(heads[1].lines().next(), Some("GET /v1/events?limit=1&starting_after=evt_1 HTTP/1.1"));
+}
@@ -1,4 +1,5 @@
 extern crate serde_json as json;
+extern crate serde_qs as qs;
 extern crate stripe;
 
 #[test]
@@ -32,3 +33,385 @@ fn deserialize_currency() {
     assert_eq!(json::from_str::<Currency>("\"usd\"").unwrap(), Currency::USD);
     assert_eq!(json::from_str::<Currency>("\"zmw\"").unwrap(), Currency::ZMW);
 }
+
+#[test]
+fn deserialize_event_type() {
+    use stripe::EventType;
+    assert_eq!(json::from_str::<EventType>("\"charge.succeeded\"").unwrap(), EventType::ChargeSucceeded);
+    assert_eq!(
+        json::from_str::<EventType>("\"customer.subscription.trial_will_end\"").unwrap(),
+        EventType::CustomerSubscriptionTrialWillEnd
+    );
+    assert_eq!(
+        json::from_str::<EventType>("\"checkout.session.completed\"").unwrap(),
+        EventType::CheckoutSessionCompleted
+    );
+    assert_eq!(
+        json::from_str::<EventType>("\"subscription_schedule.released\"").unwrap(),
+        EventType::SubscriptionScheduleReleased
+    );
+    assert_eq!(
+        json::from_str::<EventType>("\"treasury.financial_account.created\"").unwrap(),
+        EventType::Unknown("treasury.financial_account.created".to_string())
+    );
+}
+
+#[test]
+fn serialize_event_type() {
+    use stripe::EventType;
+    assert_eq!(json::to_string(&EventType::InvoiceItemCreated).unwrap(), "\"invoiceitem.created\"");
+    assert_eq!(json::to_string(&EventType::CustomerTaxIdCreated).unwrap(), "\"customer.tax_id.created\"");
+    assert_eq!(
+        json::to_string(&EventType::Unknown("treasury.financial_account.created".to_string())).unwrap(),
+        "\"treasury.financial_account.created\""
+    );
+}
+
+#[test]
+fn deserialize_event() {
+    use stripe::{Event, EventObject, EventType};
+
+    let event = json::from_str::<Event>(
+        r#"{
+        "id": "evt_123",
+        "object": "event",
+        "api_version": "2017-06-05",
+        "created": 1502740000,
+        "data": {
+            "object": {
+                "id": "re_123",
+                "object": "refund",
+                "amount": 500,
+                "balance_transaction": "txn_123",
+                "charge": "ch_123",
+                "created": 1502740000,
+                "currency": "usd",
+                "description": "",
+                "metadata": {"order_id": "6735"},
+                "reason": null,
+                "receipt_number": null,
+                "status": "succeeded"
+            },
+            "previous_attributes": {"metadata": {}}
+        },
+        "livemode": false,
+        "pending_webhooks": 1,
+        "request": {"id": "req_123", "idempotency_key": null},
+        "type": "charge.refund.updated"
+    }"#,
+    ).unwrap();
+    assert_eq!(event.event_type, EventType::ChargeRefundUpdated);
+    assert_eq!(event.request.unwrap().id, Some("req_123".to_string()));
+    match event.data.object {
+        EventObject::Refund(ref refund) => assert_eq!(refund.amount, 500),
+        ref object => panic!("expected a refund, got {:?}", object),
+    }
+    assert!(event.data.previous_attributes.is_some());
+
+    let event = json::from_str::<Event>(
+        r#"{
+        "id": "evt_456",
+        "object": "event",
+        "api_version": "2017-06-05",
+        "created": 1502740000,
+        "data": {"object": {"id": "po_123", "object": "payout", "amount": 1100}},
+        "livemode": false,
+        "pending_webhooks": 0,
+        "request": null,
+        "type": "payout.paid"
+    }"#,
+    ).unwrap();
+    match event.data.object {
+        EventObject::Unknown(ref object) => assert_eq!(object["id"], "po_123"),
+        ref object => panic!("expected an unknown object, got {:?}", object),
+    }
+}
+
+#[test]
+fn serialize_list_params() {
+    use stripe::{InvoiceListParams, ListParams, RangeQuery};
+
+    let params = InvoiceListParams {
+        list: ListParams {
+            limit: Some(10),
+            created: Some(RangeQuery::between(1502740000, 1502750000)),
+            ..Default::default()
+        },
+        customer: Some("cus_123"),
+        ..Default::default()
+    };
+    assert_eq!(
+        qs::to_string(&params).unwrap(),
+        "limit=10&created%5Bgte%5D=1502740000&created%5Blt%5D=1502750000&customer=cus_123"
+    );
+
+    let params = ListParams { created: Some(RangeQuery::eq(1502740000)), ..Default::default() };
+    assert_eq!(qs::to_string(&params).unwrap(), "created=1502740000");
+}
+
+#[test]
+fn serialize_plan_tiers() {
+    use stripe::{BillingScheme, PlanParams, TierParams, TiersMode, UpTo};
+
+    let params = PlanParams {
+        billing_scheme: Some(BillingScheme::Tiered),
+        tiers: Some(vec![
+            TierParams { up_to: UpTo::Max(1000), flat_amount: None, unit_amount: Some(10) },
+            TierParams { up_to: UpTo::Inf, flat_amount: Some(500), unit_amount: None },
+        ]),
+        tiers_mode: Some(TiersMode::Graduated),
+        ..Default::default()
+    };
+    assert_eq!(
+        qs::to_string(&params).unwrap(),
+        "billing_scheme=tiered&tiers%5B0%5D%5Bup_to%5D=1000&tiers%5B0%5D%5Bunit_amount%5D=10\
+         &tiers%5B1%5D%5Bup_to%5D=inf&tiers%5B1%5D%5Bflat_amount%5D=500&tiers_mode=graduated"
+    );
+}
+
+#[test]
+fn deserialize_expandable() {
+    use stripe::{Customer, Expandable, Identifiable, Refund};
+
+    let refund = json::from_str::<Expandable<Refund>>("\"re_123\"").unwrap();
+    assert_eq!(refund.id(), "re_123");
+    assert!(refund.as_object().is_none());
+
+    let refund = json::from_str::<Expandable<Refund>>(
+        r#"{
+        "id": "re_123",
+        "object": "refund",
+        "amount": 500,
+        "balance_transaction": "txn_123",
+        "charge": "ch_123",
+        "created": 1502740000,
+        "currency": "usd",
+        "description": "",
+        "metadata": {},
+        "reason": null,
+        "receipt_number": null,
+        "status": "succeeded"
+    }"#,
+    ).unwrap();
+    assert_eq!(refund.id(), "re_123");
+    assert_eq!(refund.as_object().map(|refund| refund.amount), Some(500));
+
+    let customer = json::from_str::<Expandable<Customer>>(r#"{"id": "cus_123", "object": "customer", "deleted": true}"#)
+        .unwrap();
+    assert_eq!(customer.id(), "cus_123");
+    assert!(customer.as_object().is_none());
+}
+
+#[test]
+fn deserialize_customer_sources() {
+    use stripe::{Customer, Identifiable, Source};
+
+    let customer: Customer = json::from_str(
+        r#"{
+        "id": "cus_123",
+        "object": "customer",
+        "account_balance": 0,
+        "business_vat_id": null,
+        "created": 1502740000,
+        "currency": null,
+        "default_source": "src_123",
+        "delinquent": false,
+        "discount": null,
+        "email": null,
+        "livemode": false,
+        "metadata": {},
+        "shipping": null,
+        "sources": {
+            "object": "list",
+            "data": [{"id": "src_123", "object": "source", "type": "sepa_debit", "status": "chargeable"}],
+            "has_more": false,
+            "url": "/v1/customers/cus_123/sources"
+        },
+        "subscriptions": {"object": "list", "data": [], "has_more": false, "url": "/v1/customers/cus_123/subscriptions"}
+    }"#,
+    ).unwrap();
+    assert_eq!(customer.email, None);
+    assert_eq!(customer.default_source.map(|source| source.id().to_string()), Some("src_123".to_string()));
+    match customer.sources.data[0] {
+        Source::Unknown(ref source) => assert_eq!(source["type"], "sepa_debit"),
+        ref source => panic!("expected an unknown source, got {:?}", source),
+    }
+    assert_eq!(customer.sources.data[0].id(), "src_123");
+}
+
+#[test]
+fn deserialize_payment_intent_next_action() {
+    use stripe::{ConfirmationMethod, NextActionType, PaymentIntent, PaymentIntentStatus};
+
+    let payment_intent = |confirmation_method: &str, status: &str, next_action: &str| {
+        format!(
+            r#"{{
+            "id": "pi_123",
+            "object": "payment_intent",
+            "amount": 500,
+            "amount_capturable": 0,
+            "amount_received": 0,
+            "capture_method": "automatic",
+            "charges": {{"object": "list", "data": [], "has_more": false, "url": "/v1/charges?payment_intent=pi_123"}},
+            "confirmation_method": "{}",
+            "created": 1502740000,
+            "currency": "usd",
+            "livemode": false,
+            "metadata": {{}},
+            "status": "{}",
+            {}
+        }}"#,
+            confirmation_method, status, next_action
+        )
+    };
+
+    let json = payment_intent(
+        "automatic",
+        "requires_action",
+        r#""next_action": {"type": "redirect_to_url", "redirect_to_url": {"return_url": null, "url": "https://hooks.stripe.com/1"}}"#,
+    );
+    let intent: PaymentIntent = json::from_str(&json).unwrap();
+    assert_eq!(intent.confirmation_method, ConfirmationMethod::Automatic);
+    assert_eq!(intent.status, PaymentIntentStatus::RequiresAction);
+    let next_action = intent.next_action.unwrap();
+    assert_eq!(next_action.action_type, NextActionType::RedirectToUrl);
+    assert_eq!(next_action.redirect_to_url.unwrap().url, "https://hooks.stripe.com/1");
+
+    // NOTE: The shape returned by API versions before 2019-02-11
+    let json = payment_intent(
+        "publishable",
+        "requires_source_action",
+        r#""next_source_action": {"type": "authorize_with_url", "authorize_with_url": {"return_url": null, "url": "https://hooks.stripe.com/2"}}"#,
+    );
+    let intent: PaymentIntent = json::from_str(&json).unwrap();
+    assert_eq!(intent.confirmation_method, ConfirmationMethod::Publishable);
+    assert_eq!(intent.status, PaymentIntentStatus::RequiresSourceAction);
+    let next_action = intent.next_action.unwrap();
+    assert_eq!(next_action.action_type, NextActionType::AuthorizeWithUrl);
+    assert_eq!(next_action.authorize_with_url.unwrap().url, "https://hooks.stripe.com/2");
+
+    let json = payment_intent("automatic", "requires_something_new", r#""next_action": null"#);
+    let intent: PaymentIntent = json::from_str(&json).unwrap();
+    assert_eq!(intent.status, PaymentIntentStatus::Unknown("requires_something_new".to_string()));
+}
+
+#[test]
+fn deserialize_setup_intent_status() {
+    use stripe::{SetupIntent, SetupIntentStatus};
+
+    let setup_intent = |status: &str| {
+        format!(
+            r#"{{
+            "id": "seti_123",
+            "object": "setup_intent",
+            "created": 1502740000,
+            "livemode": false,
+            "metadata": {{}},
+            "payment_method_types": ["card"],
+            "status": "{}",
+            "usage": "off_session"
+        }}"#,
+            status
+        )
+    };
+
+    let intent: SetupIntent = json::from_str(&setup_intent("requires_payment_method")).unwrap();
+    assert_eq!(intent.status, SetupIntentStatus::RequiresPaymentMethod);
+    let intent: SetupIntent = json::from_str(&setup_intent("requires_something_new")).unwrap();
+    assert_eq!(intent.status, SetupIntentStatus::Unknown("requires_something_new".to_string()));
+    assert_eq!(json::to_string(&intent.status).unwrap(), "\"requires_something_new\"");
+}
+
+#[test]
+fn deserialize_invoice_status() {
+    use stripe::InvoiceStatus;
+
+    let status: InvoiceStatus = json::from_str("\"uncollectible\"").unwrap();
+    assert_eq!(status, InvoiceStatus::Uncollectible);
+    let status: InvoiceStatus = json::from_str("\"deleted\"").unwrap();
+    assert_eq!(status, InvoiceStatus::Unknown("deleted".to_string()));
+    assert_eq!(status.to_string(), "deleted");
+}
+
+#[test]
+fn serialize_upcoming_invoice_items() {
+    use stripe::{InvoiceUpcomingItemParams, InvoiceUpcomingParams};
+
+    let params = InvoiceUpcomingParams {
+        customer: "cus_123",
+        subscription: Some("sub_123"),
+        subscription_items: Some(vec![
+            InvoiceUpcomingItemParams { id: Some("si_123"), plan: Some("gold"), ..Default::default() },
+            InvoiceUpcomingItemParams { id: Some("si_456"), deleted: Some(true), ..Default::default() },
+        ]),
+        ..Default::default()
+    };
+    assert_eq!(
+        qs::to_string(&params).unwrap(),
+        "customer=cus_123&subscription=sub_123&subscription_items%5B0%5D%5Bid%5D=si_123\
+         &subscription_items%5B0%5D%5Bplan%5D=gold&subscription_items%5B1%5D%5Bid%5D=si_456\
+         &subscription_items%5B1%5D%5Bdeleted%5D=true"
+    );
+}
+
+#[test]
+fn deserialize_payment_method_type() {
+    use stripe::{PaymentMethod, PaymentMethodType};
+
+    let payment_method = |payment_method_type: &str| {
+        format!(
+            r#"{{
+            "id": "pm_123",
+            "object": "payment_method",
+            "billing_details": {{"address": null, "email": null, "name": null, "phone": null}},
+            "created": 1502740000,
+            "customer": null,
+            "livemode": false,
+            "metadata": {{}},
+            "type": "{}"
+        }}"#,
+            payment_method_type
+        )
+    };
+
+    let method: PaymentMethod = json::from_str(&payment_method("sepa_debit")).unwrap();
+    assert_eq!(method.payment_method_type, PaymentMethodType::SepaDebit);
+    let method: PaymentMethod = json::from_str(&payment_method("sofort")).unwrap();
+    assert_eq!(method.payment_method_type, PaymentMethodType::Unknown("sofort".to_string()));
+    assert_eq!(json::to_string(&method.payment_method_type).unwrap(), "\"sofort\"");
+}
+
+#[test]
+fn deserialize_event_object() {
+    use stripe::EventObject;
+
+    let object = json::from_str::<EventObject>(
+        r#"{
+        "id": "ba_123",
+        "object": "bank_account",
+        "account_holder_name": "Jane Austen",
+        "account_holder_type": "individual",
+        "bank_name": "STRIPE TEST BANK",
+        "country": "US",
+        "currency": "usd",
+        "customer": "cus_123",
+        "fingerprint": "1JWtPxqbdX5Gamtc",
+        "last4": "6789",
+        "metadata": {},
+        "routing_number": "110000000",
+        "status": "new"
+    }"#,
+    ).unwrap();
+    match object {
+        EventObject::BankAccount(ref bank_account) => assert_eq!(bank_account.last4, "6789"),
+        ref object => panic!("expected a bank account, got {:?}", object),
+    }
+
+    let object = json::from_str::<EventObject>(
+        r#"{"id": "src_123", "object": "source", "type": "three_d_secure", "status": "chargeable"}"#,
+    ).unwrap();
+    match object {
+        EventObject::Unknown(ref object) => assert_eq!(object["id"], "src_123"),
+        ref object => panic!("expected an unknown object, got {:?}", object),
+    }
+}
@@ -1,13 +1,167 @@
 extern crate serde_json as json;
 extern crate stripe;
 
+mod common;
+
+use common::mock_server;
+use std::time::Duration;
+
 #[test]
-#[ignore] // TODO: Figure out how to run this test with an sk_key, etc
 fn customer_delete() {
-    let client = stripe::Client::new("sk_key");
+    let (url, request) = mock_server(vec![(200, r#"{"deleted": true, "id": "cus_example_id"}"#)]);
+    let client = stripe::Client::builder("sk_key").api_base(url).build();
     let result = stripe::Customer::delete(&client, "cus_example_id");
     match result {
         Ok(deleted) => assert!(deleted.deleted, "Customer wasn't deleted"),
         Err(err) => assert!(false, format!("{}", err)),
     }
+    let head = request.recv().unwrap();
+    assert_eq!(head.lines().next(), Some("DELETE /v1/customers/cus_example_id HTTP/1.1"));
+    assert!(head.contains(&format!("Stripe-Version: {}", stripe::API_VERSION)));
+}
+
+#[test]
+fn customer_delete_retry() {
+    let rate_limited = r#"{"error": {"type": "rate_limit_error", "message": "Too many requests"}}"#;
+    let (url, request) = mock_server(vec![(429, rate_limited), (200, r#"{"deleted": true, "id": "cus_example_id"}"#)]);
+    let policy = stripe::RetryPolicy {
+        max_attempts: 2,
+        backoff_base: Duration::from_millis(1),
+        backoff_cap: Duration::from_millis(1),
+        jitter: false,
+    };
+    let client = stripe::Client::builder("sk_key").api_base(url).retry_policy(policy).build();
+    let deleted = stripe::Customer::delete(&client, "cus_example_id").unwrap();
+    assert!(deleted.deleted, "Customer wasn't deleted");
+    assert_eq!(request.iter().count(), 2);
+}
+
+#[test]
+fn customer_create_no_retry() {
+    let rate_limited = r#"{"error": {"type": "rate_limit_error", "message": "Too many requests"}}"#;
+    let (url, request) = mock_server(vec![(429, rate_limited)]);
+    let client = stripe::Client::builder("sk_key").api_base(url).retry_policy(stripe::RetryPolicy::default()).build();
+    let result = stripe::Customer::create(&client, stripe::CustomerParams::default());
+    match result {
+        Err(stripe::Error::Stripe(err)) => assert_eq!(err.error_type, stripe::ErrorType::RateLimit),
+        _ => assert!(false, "expected a rate limit error"),
+    }
+    assert_eq!(request.iter().count(), 1);
+}
+
+#[test]
+fn customer_update_idempotency_key() {
+    let unavailable = r#"{"error": {"type": "api_error", "message": "Service unavailable"}}"#;
+    let (url, request) = mock_server(vec![(503, unavailable), (200, r#"{"id": "cus_example_id"}"#)]);
+    let policy = stripe::RetryPolicy {
+        max_attempts: 2,
+        backoff_base: Duration::from_millis(1),
+        backoff_cap: Duration::from_millis(1),
+        jitter: false,
+    };
+    let client = stripe::Client::builder("sk_key").api_base(url).retry_policy(policy).build();
+    let client = client.with(stripe::Params {
+        stripe_version: Some("2017-08-15".to_string()),
+        idempotency_key: Some(stripe::IdempotencyKey::Auto),
+        ..Default::default()
+    });
+    let params = stripe::CustomerParams { description: Some("example"), ..Default::default() };
+    let customer: json::Value = client.post("/customers/cus_example_id", params).unwrap();
+    assert_eq!(customer["id"], "cus_example_id");
+
+    let heads: Vec<String> = request.iter().collect();
+    let keys: Vec<&str> = heads
+        .iter()
+        .map(|head| head.lines().find(|line| line.starts_with("Idempotency-Key:")).unwrap())
+        .collect();
+    assert_eq!(keys.len(), 2);
+    assert_eq!(keys[0], keys[1]);
+    assert!(heads.iter().all(|head| head.contains("Stripe-Version: 2017-08-15")));
+}
+
+#[test]
+fn customer_create_auto_idempotency_with_params() {
+    let unavailable = r#"{"error": {"type": "api_error", "message": "Service unavailable"}}"#;
+    let (url, request) = mock_server(vec![(503, unavailable), (200, r#"{"id": "cus_example_id"}"#)]);
+    let policy = stripe::RetryPolicy {
+        max_attempts: 2,
+        backoff_base: Duration::from_millis(1),
+        backoff_cap: Duration::from_millis(1),
+        jitter: false,
+    };
+    let mut client = stripe::Client::builder("sk_key").api_base(url).retry_policy(policy).build();
+    client.enable_auto_idempotency();
+    let client = client.with(stripe::Params { stripe_account: Some("acct_123".to_string()), ..Default::default() });
+    let customer: json::Value = client.post("/customers", stripe::CustomerParams::default()).unwrap();
+    assert_eq!(customer["id"], "cus_example_id");
+
+    let heads: Vec<String> = request.iter().collect();
+    assert_eq!(heads.len(), 2);
+    assert!(heads.iter().all(|head| head.contains("Idempotency-Key:") && head.contains("Stripe-Account: acct_123")));
+}
+
+#[test]
+fn event_list_paginate() {
+    let first = r#"{
+        "object": "list",
+        "url": "/v1/events",
+        "has_more": true,
+        "data": [{"id": "evt_1", "created": 1502740000, "livemode": false, "pending_webhooks": 0,
+                  "type": "payout.paid", "data": {"object": {"id": "po_1", "object": "payout"}}}]
+    }"#;
+    let second = r#"{
+        "object": "list",
+        "url": "/v1/events",
+        "has_more": false,
+        "data": [{"id": "evt_2", "created": 1502740000, "livemode": false, "pending_webhooks": 0,
+                  "type": "payout.paid", "data": {"object": {"id": "po_2", "object": "payout"}}}]
+    }"#;
+    let (url, request) = mock_server(vec![(200, first), (200, second)]);
+    let client = stripe::Client::builder("sk_key").api_base(url).build();
+    let params = stripe::EventListParams {
+        list: stripe::ListParams { limit: Some(1), ..Default::default() },
+        ..Default::default()
+    };
+    let events = stripe::Event::list(&client, params).unwrap();
+    let ids: Vec<String> = events.paginate(&client).map(|event| event.unwrap().id).collect();
+    assert_eq!(ids, vec!["evt_1", "evt_2"]);
+
+    let heads: Vec<String> = request.iter().collect();
+    assert_eq!(heads[0].lines().next(), Some("GET /v1/events?limit=1 HTTP/1.1"));
+    assert_eq!(heads[1].lines().next(), Some("GET /v1/events?limit=1&starting_after=evt_1 HTTP/1.1"));
+}
+
+#[test]
+fn upcoming_invoice_lines_paginate() {
+    let first = r#"{
+        "object": "list",
+        "url": "/v1/invoices/upcoming/lines?customer=cus_1",
+        "has_more": true,
+        "data": [{"id": "ii_1", "amount": 500, "currency": "usd", "discountable": true, "livemode": false,
+                  "metadata": {}, "period": {"start": 1502740000, "end": 1502750000}, "proration": false}]
+    }"#;
+    let second = r#"{
+        "object": "list",
+        "url": "/v1/invoices/upcoming/lines?customer=cus_1",
+        "has_more": false,
+        "data": [{"id": "ii_2", "amount": 500, "currency": "usd", "discountable": true, "livemode": false,
+                  "metadata": {}, "period": {"start": 1502740000, "end": 1502750000}, "proration": false}]
+    }"#;
+    let (url, request) = mock_server(vec![(200, first), (200, second)]);
+    let client = stripe::Client::builder("sk_key").api_base(url).build();
+    let params = stripe::InvoiceListLinesParams {
+        list: stripe::ListParams { limit: Some(1), ..Default::default() },
+        customer: Some("cus_1"),
+        ..Default::default()
+    };
+    let lines = stripe::Invoice::lines(&client, "upcoming", params).unwrap();
+    let ids: Vec<String> = lines.paginate(&client).map(|line| line.unwrap().id).collect();
+    assert_eq!(ids, vec!["ii_1", "ii_2"]);
+
+    let heads: Vec<String> = request.iter().collect();
+    assert_eq!(heads[0].lines().next(), Some("GET /v1/invoices/upcoming/lines?limit=1&customer=cus_1 HTTP/1.1"));
+    assert_eq!(
+        heads[1].lines().next(),
+        Some("GET /v1/invoices/upcoming/lines?limit=1&customer=cus_1&starting_after=ii_1 HTTP/1.1")
+    );
 }